@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests;
+
+use crate::models::Answer;
+use crate::runner::output::answer_to_csv;
+use crate::runner::RunDaySolution;
+
+/// Phases faster than this, in microseconds, on both runs are too noisy to be compared
+pub const MIN_SIGNIFICANT_TIME: u128 = 10;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AnswerChange {
+    pub part: usize,
+    pub baseline: Answer,
+    pub current: Answer,
+}
+
+/// Timing of a phase in the baseline and in the current run, in microseconds
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PhaseChange {
+    pub phase: &'static str,
+    pub baseline: u128,
+    pub current: u128,
+}
+
+impl PhaseChange {
+    /// Relative change compared to the baseline, in percent
+    pub fn change_percent(&self) -> f64 {
+        if self.baseline == 0 {
+            return f64::INFINITY;
+        }
+        (self.current as f64 - self.baseline as f64) * 100.0 / self.baseline as f64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DayComparison {
+    pub day: usize,
+    pub input: String,
+    pub answer_changes: Vec<AnswerChange>,
+    /// Phases slower than the baseline by more than the threshold
+    pub regressions: Vec<PhaseChange>,
+    /// Phases faster than the baseline by more than the threshold
+    pub improvements: Vec<PhaseChange>,
+}
+
+impl DayComparison {
+    pub fn has_regression(&self) -> bool {
+        !self.answer_changes.is_empty() || !self.regressions.is_empty()
+    }
+}
+
+/// Compare a run with a baseline, days and inputs missing from either side are ignored.
+///
+/// A phase regressed when it is slower than its baseline timing by more than `threshold` percent.
+pub fn compare(baseline: &[RunDaySolution], current: &[RunDaySolution], threshold: f64) -> Vec<DayComparison> {
+    current
+        .iter()
+        .filter_map(|current| {
+            let baseline = baseline
+                .iter()
+                .find(|baseline| baseline.day == current.day && baseline.input == current.input)?;
+            let mut comparison = DayComparison {
+                day: current.day,
+                input: current.input.clone(),
+                ..Default::default()
+            };
+
+            for (part, baseline_answer, current_answer) in [
+                (1, &baseline.part_01_sol, &current.part_01_sol),
+                (2, &baseline.part_02_sol, &current.part_02_sol),
+            ] {
+                // The baseline does not keep the type of the answers, they are compared as written in it
+                if answer_to_csv(baseline_answer) != answer_to_csv(current_answer) {
+                    comparison.answer_changes.push(AnswerChange {
+                        part,
+                        baseline: baseline_answer.clone(),
+                        current: current_answer.clone(),
+                    });
+                }
+            }
+
+            for (phase, baseline_time, current_time) in [
+                ("parse", baseline.parse_time, current.parse_time),
+                ("prep", baseline.prep_time, current.prep_time),
+                ("part 1", baseline.part_01_time, current.part_01_time),
+                ("part 2", baseline.part_02_time, current.part_02_time),
+                ("total", baseline.total_time(), current.total_time()),
+            ] {
+                if baseline_time.max(current_time) < MIN_SIGNIFICANT_TIME {
+                    continue;
+                }
+
+                let change = PhaseChange {
+                    phase,
+                    baseline: baseline_time,
+                    current: current_time,
+                };
+                if change.change_percent() > threshold {
+                    comparison.regressions.push(change);
+                } else if change.change_percent() < -threshold {
+                    comparison.improvements.push(change);
+                }
+            }
+
+            Some(comparison)
+        })
+        .collect()
+}
@@ -0,0 +1,81 @@
+use crate::baseline::{compare, AnswerChange, PhaseChange};
+use crate::models::Answer;
+use crate::runner::output::{from_csv, to_csv};
+use crate::runner::RunDaySolution;
+
+fn solution(day: usize, part_02_sol: Answer, parse_time: u128, part_01_time: u128) -> RunDaySolution {
+    RunDaySolution {
+        day,
+        input: format!("day{day:0>2}"),
+        part_01_sol: "4,6,\"3\"".into(),
+        part_02_sol,
+        parse_time,
+        prep_time: 0,
+        part_01_time,
+        part_02_time: 5,
+        memory: None,
+    }
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let solutions = vec![
+        solution(1, Answer::NotApplicable, 1, 2),
+        solution(2, Answer::Integer(-3), 1, 2),
+        solution(3, Answer::BigInteger(u64::MAX as u128), 1, 2),
+    ];
+    assert_eq!(from_csv(&to_csv(&solutions)).unwrap(), solutions);
+
+    let error = from_csv("day,part_01_sol\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 5));
+
+    let csv = format!("{}\n1,day01,2,3,4,x,6,7,8\n", to_csv(&[]));
+    let error = from_csv(&csv).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 15));
+}
+
+#[test]
+fn test_compare() {
+    let baseline = vec![solution(1, 2.into(), 100, 100), solution(2, 2.into(), 100, 100)];
+    let current = vec![solution(1, 3.into(), 105, 150), solution(3, 2.into(), 100, 100)];
+
+    let comparisons = compare(&baseline, &current, 10.0);
+    assert_eq!(comparisons.len(), 1);
+
+    let comparison = &comparisons[0];
+    assert!(comparison.has_regression());
+    assert_eq!(
+        comparison.answer_changes,
+        vec![AnswerChange {
+            part: 2,
+            baseline: 2.into(),
+            current: 3.into(),
+        }]
+    );
+    assert_eq!(
+        comparison.regressions,
+        vec![
+            PhaseChange {
+                phase: "part 1",
+                baseline: 100,
+                current: 150,
+            },
+            PhaseChange {
+                phase: "total",
+                baseline: 205,
+                current: 260,
+            },
+        ]
+    );
+    assert!(comparison.improvements.is_empty());
+}
+
+#[test]
+fn test_compare_text_answers_from_csv() {
+    let solutions = vec![solution(1, Answer::Text("5".to_string()), 100, 100)];
+    let baseline = from_csv(&to_csv(&solutions)).unwrap();
+
+    let comparisons = compare(&baseline, &solutions, 10.0);
+    assert_eq!(comparisons.len(), 1);
+    assert!(comparisons[0].answer_changes.is_empty());
+}
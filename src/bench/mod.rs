@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::errors::ParseError;
+use crate::registry::SolverInfo;
+
+/// How many times a day is run by the benchmark
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BenchConfig {
+    /// Runs done before measuring, to warm up caches and the allocator
+    pub warmup: usize,
+    /// Measured runs, ignored if a duration is set
+    pub iterations: usize,
+    /// If set, keep running until this wall-clock duration is reached (at least once)
+    pub duration: Option<Duration>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+            duration: None,
+        }
+    }
+}
+
+/// Statistics of a phase, in nanoseconds
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PhaseStats {
+    pub min: u128,
+    pub median: u128,
+    pub mean: u128,
+    pub p95: u128,
+}
+
+impl PhaseStats {
+    /// Compute the statistics of a non-empty list of samples
+    pub fn from_samples(samples: &[u128]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        // Nearest-rank percentile
+        let p95_rank = (count * 95).div_ceil(100).max(1);
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<u128>() / count as u128,
+            p95: sorted[p95_rank - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BenchResult {
+    pub day: usize,
+    pub iterations: usize,
+    pub parse: PhaseStats,
+    pub prepare: PhaseStats,
+    pub part_one: PhaseStats,
+    pub part_two: PhaseStats,
+}
+
+/// Timings of a single run, in nanoseconds
+struct RunTimings {
+    parse: u128,
+    prepare: u128,
+    part_one: u128,
+    part_two: u128,
+}
+
+fn run_once(info: &SolverInfo, input: &str) -> Result<RunTimings, ParseError> {
+    let solver = info.solver();
+    let input = input.to_string();
+
+    let now = Instant::now();
+    let parsed = solver.parse(input)?;
+    let parse = now.elapsed().as_nanos();
+
+    let now = Instant::now();
+    let prepared = parsed.prepare();
+    let prepare = now.elapsed().as_nanos();
+
+    let now = Instant::now();
+    black_box(prepared.solve_part_one());
+    let part_one = now.elapsed().as_nanos();
+
+    let now = Instant::now();
+    black_box(prepared.solve_part_two());
+    let part_two = now.elapsed().as_nanos();
+
+    Ok(RunTimings {
+        parse,
+        prepare,
+        part_one,
+        part_two,
+    })
+}
+
+/// Run a day several times and compute statistics for every phase
+pub fn bench_day(info: &SolverInfo, input: &str, config: &BenchConfig) -> Result<BenchResult, ParseError> {
+    for _ in 0..config.warmup {
+        run_once(info, input)?;
+    }
+
+    let mut runs = vec![];
+    let start = Instant::now();
+    loop {
+        runs.push(run_once(info, input)?);
+
+        let done = match config.duration {
+            Some(duration) => start.elapsed() >= duration,
+            None => runs.len() >= config.iterations.max(1),
+        };
+        if done {
+            break;
+        }
+    }
+
+    let stats = |phase: fn(&RunTimings) -> u128| PhaseStats::from_samples(&runs.iter().map(phase).collect::<Vec<_>>());
+
+    Ok(BenchResult {
+        day: info.day,
+        iterations: runs.len(),
+        parse: stats(|run| run.parse),
+        prepare: stats(|run| run.prepare),
+        part_one: stats(|run| run.part_one),
+        part_two: stats(|run| run.part_two),
+    })
+}
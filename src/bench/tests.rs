@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::bench::{bench_day, BenchConfig, PhaseStats};
+use crate::registry::get;
+
+#[test]
+fn test_phase_stats() {
+    let stats = PhaseStats::from_samples(&[5, 1, 4, 2, 3]);
+    assert_eq!(
+        stats,
+        PhaseStats {
+            min: 1,
+            median: 3,
+            mean: 3,
+            p95: 5,
+        }
+    );
+
+    let samples: Vec<u128> = (1..=100).collect();
+    let stats = PhaseStats::from_samples(&samples);
+    assert_eq!((stats.median, stats.p95), (50, 95));
+}
+
+#[test]
+fn test_bench_day() {
+    let info = get(1).unwrap();
+    let input = include_str!("../../input_examples/day01");
+
+    let config = BenchConfig {
+        warmup: 1,
+        iterations: 4,
+        duration: None,
+    };
+    assert_eq!(bench_day(info, input, &config).unwrap().iterations, 4);
+
+    let config = BenchConfig {
+        warmup: 0,
+        iterations: 1,
+        duration: Some(Duration::from_millis(5)),
+    };
+    assert!(bench_day(info, input, &config).unwrap().iterations >= 1);
+    assert!(bench_day(info, "x", &config).is_err());
+}
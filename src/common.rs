@@ -1,13 +1,46 @@
-use crate::models::AdventSolution;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
 
-pub fn test_part_one_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: i128) {
-    solution.parse(data.to_string());
-    solution.prepare();
-    assert_eq!(solution.solve_part_one(), expected_result);
+use crate::models::{AdventSolution, Answer};
+use crate::registry;
+
+pub fn test_part_one_common<S: AdventSolution>(data: &str, expected_result: impl Into<Answer>) {
+    let prepared = S::prepare(S::parse(data.to_string()).unwrap());
+    assert_eq!(S::solve_part_one(&prepared), expected_result.into());
+}
+
+pub fn test_part_two_common<S: AdventSolution>(data: &str, expected_result: impl Into<Answer>) {
+    let prepared = S::prepare(S::parse(data.to_string()).unwrap());
+    assert_eq!(S::solve_part_two(&prepared), expected_result.into());
 }
 
-pub fn test_part_two_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: i128) {
-    solution.parse(data.to_string());
-    solution.prepare();
-    assert_eq!(solution.solve_part_two(), expected_result);
+pub fn test_parse_error_common<S: AdventSolution>(data: &str, line: usize, column: usize) {
+    let Err(error) = S::parse(data.to_string()) else {
+        panic!("expected a parse error");
+    };
+    assert_eq!((error.line, error.column), (line, column), "{error}");
+}
+
+/// Check that every example input of `day` gives the same model once serialized and parsed again
+pub fn test_round_trip_common<S: AdventSolution>(day: usize)
+where
+    S::Parsed: PartialEq + Debug,
+{
+    let inputs = registry::get(day)
+        .unwrap()
+        .find_inputs(Path::new("input_examples"))
+        .unwrap();
+    assert!(!inputs.is_empty(), "day {day} has no example input");
+
+    for path in inputs {
+        let parsed = S::parse(fs::read_to_string(&path).unwrap()).unwrap();
+        let serialized = S::serialize(&parsed);
+        assert_eq!(
+            S::parse(serialized.clone()).unwrap(),
+            parsed,
+            "{} is serialized as:\n{serialized}",
+            path.display()
+        );
+    }
 }
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests;
+
+use crate::errors::ParseError;
+use crate::models::Answer;
+use crate::registry::SolverInfo;
+use crate::runner::Part;
+
+/// Answer of a part that differs from the answer of the reference solver
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Divergence {
+    pub part: Part,
+    /// Answer of the reference solver
+    pub expected: Answer,
+    /// Answer of the optimised solver
+    pub actual: Answer,
+}
+
+/// Comparison of a solver with its reference solver on one input
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CrossCheck {
+    /// Parts that have a reference solver, nothing was checked if it is empty
+    pub checked: Vec<Part>,
+    pub divergences: Vec<Divergence>,
+}
+
+impl CrossCheck {
+    pub fn passed(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// Run the solver and the reference solver of a day on the same input and compare their answers
+pub fn crosscheck(info: &SolverInfo, input: String) -> Result<CrossCheck, ParseError> {
+    let parsed = info.solver().parse(input)?;
+    let expected = [
+        (Part::One, parsed.reference_part_one()),
+        (Part::Two, parsed.reference_part_two()),
+    ];
+    if expected.iter().all(|(_, answer)| answer.is_none()) {
+        return Ok(CrossCheck::default());
+    }
+
+    // Only the parts that have a reference solver are solved
+    let prepared = parsed.prepare();
+    let mut crosscheck = CrossCheck::default();
+    for (part, expected) in expected {
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let actual = match part {
+            Part::One => prepared.solve_part_one(),
+            Part::Two => prepared.solve_part_two(),
+        };
+        crosscheck.checked.push(part);
+        if actual != expected {
+            crosscheck.divergences.push(Divergence { part, expected, actual });
+        }
+    }
+
+    Ok(crosscheck)
+}
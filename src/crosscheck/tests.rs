@@ -0,0 +1,38 @@
+use crate::crosscheck::crosscheck;
+use crate::generate::Rng;
+use crate::registry;
+use crate::runner::Part;
+
+#[test]
+fn test_crosscheck_without_reference() {
+    let info = registry::get(1).unwrap();
+
+    let crosscheck = crosscheck(info, "3   4\n4   3\n".to_string()).unwrap();
+    assert!(crosscheck.checked.is_empty());
+    assert!(crosscheck.passed());
+}
+
+#[test]
+fn test_crosscheck_invalid_input() {
+    let info = registry::get(11).unwrap();
+
+    assert!(crosscheck(info, "125 a".to_string()).is_err());
+}
+
+#[test]
+fn test_references_agree_on_generated_inputs() {
+    for (day, size) in [(6, 12), (11, 4), (13, 30), (21, 3), (22, 10)] {
+        let info = registry::get(day).unwrap();
+        for seed in 0..3 {
+            let input = info.solver().generate(&mut Rng::new(seed), Some(size)).unwrap();
+            let crosscheck = crosscheck(info, input.clone()).unwrap();
+
+            assert!(crosscheck.checked.contains(&Part::One), "day {day} has no reference");
+            assert!(
+                crosscheck.passed(),
+                "day {day} diverges with seed {seed}: {:?}\n{input}",
+                crosscheck.divergences
+            );
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::generate::{lines, Rng};
+
+/// Two lists of location ids, the real input has 1000 lines
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(1000);
+
+    // Reuse some ids in the right list so that the similarity score is not always 0
+    let left: Vec<_> = (0..count).map(|_| rng.range(10000..100000)).collect();
+    lines(left.iter().map(|left_id| {
+        let right = if rng.chance(0.3) {
+            *rng.choose(&left)
+        } else {
+            rng.range(10000..100000)
+        };
+        format!("{left_id}   {right}")
+    }))
+}
@@ -1,29 +1,67 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day01::generator::generate;
 use crate::day01::logic::{solve_part_one, solve_part_two};
 use crate::day01::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day01::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day01 {
-    parsed_data: Option<(Vec<i64>, Vec<i64>)>,
-}
+pub struct Day01;
 
 impl AdventSolution for Day01 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = (Vec<i64>, Vec<i64>);
+    type Prepared = (Vec<i64>, Vec<i64>);
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(1))
+    }
+
+    fn serialize(lists: &Self::Parsed) -> String {
+        serialize(lists)
+    }
+
+    fn prepare(lists: Self::Parsed) -> Self::Prepared {
+        lists
+    }
+
+    fn solve_part_one(lists: &Self::Prepared) -> Answer {
+        part_one(lists).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(lists: &Self::Prepared) -> Answer {
+        part_two(lists).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the two location lists
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    Day01::parse(input.to_string())
+}
+
+/// Write the two location lists in the input format
+pub fn serialize(lists: &(Vec<i64>, Vec<i64>)) -> String {
+    serialize_input(lists)
+}
+
+/// Total distance between the two lists once sorted
+pub fn part_one(lists: &(Vec<i64>, Vec<i64>)) -> u64 {
+    solve_part_one(lists)
+}
+
+/// Similarity score of the two lists
+pub fn part_two(lists: &(Vec<i64>, Vec<i64>)) -> i64 {
+    solve_part_two(lists)
+}
@@ -0,0 +1,5 @@
+use crate::generate::lines;
+
+pub fn serialize_input((left, right): &(Vec<i64>, Vec<i64>)) -> String {
+    lines(left.iter().zip(right).map(|(left, right)| format!("{left}   {right}")))
+}
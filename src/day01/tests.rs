@@ -1,14 +1,24 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day01::Day01;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day01");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day01::default(), INPUT_EXAMPLE, 11);
+    test_part_one_common::<Day01>(INPUT_EXAMPLE, 11);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day01::default(), INPUT_EXAMPLE, 31);
+    test_part_two_common::<Day01>(INPUT_EXAMPLE, 31);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day01>("3   4\n4   x\n", 2, 5);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day01>(1);
 }
@@ -0,0 +1,25 @@
+use crate::generate::{lines, Rng};
+
+/// Reports of 5 to 8 levels, most of them safe or safe once a level is removed
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(1000);
+
+    lines((0..count).map(|_| {
+        let length = rng.range(5..9);
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+
+        let mut level = rng.signed(1..=99);
+        let mut report = vec![];
+        for _ in 0..length {
+            report.push(level.to_string());
+            // Mostly safe steps of 1 to 3, with from time to time a wrong one
+            let step = if rng.chance(0.1) {
+                rng.signed(-3..=6)
+            } else {
+                rng.signed(1..=3)
+            };
+            level += direction * step;
+        }
+        report.join(" ")
+    }))
+}
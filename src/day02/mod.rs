@@ -1,29 +1,67 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day02::generator::generate;
 use crate::day02::logic::{solve_part_one, solve_part_two};
 use crate::day02::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day02::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day02 {
-    parsed_data: Option<Vec<Vec<i64>>>,
-}
+pub struct Day02;
 
 impl AdventSolution for Day02 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<Vec<i64>>;
+    type Prepared = Vec<Vec<i64>>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(2))
+    }
+
+    fn serialize(reports: &Self::Parsed) -> String {
+        serialize(reports)
+    }
+
+    fn prepare(reports: Self::Parsed) -> Self::Prepared {
+        reports
+    }
+
+    fn solve_part_one(reports: &Self::Prepared) -> Answer {
+        part_one(reports).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(reports: &Self::Prepared) -> Answer {
+        part_two(reports).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the reports
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Day02::parse(input.to_string())
+}
+
+/// Write the reports in the input format
+pub fn serialize(reports: &[Vec<i64>]) -> String {
+    serialize_input(reports)
+}
+
+/// Number of safe reports
+pub fn part_one(reports: &[Vec<i64>]) -> usize {
+    solve_part_one(reports)
+}
+
+/// Number of safe reports when a single bad level can be removed
+pub fn part_two(reports: &[Vec<i64>]) -> usize {
+    solve_part_two(reports)
+}
@@ -1,23 +1,12 @@
-use nom::bytes::complete::tag;
-use nom::character::complete::{i64 as i64_parser, line_ending};
-use nom::combinator::{map, opt};
-use nom::multi::{many1, separated_list1};
-use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
+use crate::errors::ParseError;
+use crate::parsing::{finish, line, list, many1_complete, signed};
+
 fn parse_locations(input: &str) -> IResult<&str, Vec<i64>> {
-    map(
-        tuple((separated_list1(tag(" "), i64_parser), opt(line_ending))),
-        |(list, _)| list,
-    )
-    .parse(input)
+    line(list(" ", signed)).parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Vec<i64>> {
-    let (res, reports) = many1(parse_locations).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    reports
+pub fn parse_input(input: String) -> Result<Vec<Vec<i64>>, ParseError> {
+    finish(&input, many1_complete(parse_locations).parse(&input))
 }
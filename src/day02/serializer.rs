@@ -0,0 +1,7 @@
+use itertools::Itertools;
+
+use crate::generate::lines;
+
+pub fn serialize_input(reports: &[Vec<i64>]) -> String {
+    lines(reports.iter().map(|report| report.iter().join(" ")))
+}
@@ -0,0 +1,42 @@
+use crate::generate::{lines, Rng};
+
+/// Corrupted characters found between instructions
+const GARBAGE: &[u8] = b"!@#$%^&*[]{}<>'+";
+/// Calls that look like instructions without being valid ones
+const WORDS: [&str; 8] = [
+    "where()", "how()", "select()", "from()", "what()", "who()", "when()", "why()",
+];
+
+fn instruction(rng: &mut Rng) -> String {
+    let (left, right) = (rng.range(1..1000), rng.range(1..1000));
+    match rng.range(0..10) {
+        0 => "do()".to_string(),
+        1 => "don't()".to_string(),
+        2 => match rng.range(0..4) {
+            // Instructions that are almost valid
+            0 => format!("mul({left}*{right})"),
+            1 => format!("mul( {left},{right})"),
+            2 => format!("mul({left},{right}]"),
+            _ => format!("mul({}{left},{right})", rng.range(1..10)),
+        },
+        3 => rng.choose(&WORDS).to_string(),
+        _ => format!("mul({left},{right})"),
+    }
+}
+
+/// Corrupted memory mixing `mul`, `do` and `don't` instructions with garbage, the real input is
+/// made of 6 lines of about 700 instructions
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(700);
+
+    lines((0..6).map(|_| {
+        let mut line = String::new();
+        for _ in 0..count / 6 + 1 {
+            for _ in 0..rng.range(0..4) {
+                line.push(*rng.choose(GARBAGE) as char);
+            }
+            line.push_str(&instruction(rng));
+        }
+        line
+    }))
+}
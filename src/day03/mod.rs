@@ -1,29 +1,67 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day03::generator::generate;
 use crate::day03::logic::{solve_part_one, solve_part_two};
 use crate::day03::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day03::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day03 {
-    parsed_data: Option<String>,
-}
+pub struct Day03;
 
 impl AdventSolution for Day03 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = String;
+    type Prepared = String;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(3))
+    }
+
+    fn serialize(memory: &Self::Parsed) -> String {
+        serialize(memory)
+    }
+
+    fn prepare(memory: Self::Parsed) -> Self::Prepared {
+        memory
+    }
+
+    fn solve_part_one(memory: &Self::Prepared) -> Answer {
+        part_one(memory).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(memory: &Self::Prepared) -> Answer {
+        part_two(memory).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the corrupted memory
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    Day03::parse(input.to_string())
+}
+
+/// Write the corrupted memory in the input format
+pub fn serialize(memory: &str) -> String {
+    serialize_input(memory)
+}
+
+/// Sum of the valid multiplications
+pub fn part_one(memory: &str) -> u32 {
+    solve_part_one(memory)
+}
+
+/// Sum of the multiplications enabled by `do()` and `don't()`
+pub fn part_two(memory: &str) -> u32 {
+    solve_part_two(memory)
+}
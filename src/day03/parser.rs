@@ -1,3 +1,5 @@
-pub fn parse_input(input: String) -> String {
-    input
+use crate::errors::ParseError;
+
+pub fn parse_input(input: String) -> Result<String, ParseError> {
+    Ok(input)
 }
@@ -0,0 +1,4 @@
+pub fn serialize_input(memory: &str) -> String {
+    // The corrupted memory is kept as is
+    memory.to_string()
+}
@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day03::Day03;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day03");
@@ -6,10 +6,15 @@ static INPUT_EXAMPLE_2: &str = include_str!("../../input_examples/day03_2");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day03::default(), INPUT_EXAMPLE, 161);
+    test_part_one_common::<Day03>(INPUT_EXAMPLE, 161);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day03::default(), INPUT_EXAMPLE_2, 48);
+    test_part_two_common::<Day03>(INPUT_EXAMPLE_2, 48);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day03>(3);
 }
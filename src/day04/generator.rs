@@ -0,0 +1,8 @@
+use crate::generate::{lines, word, Rng};
+
+/// Square grid of the letters of XMAS, the real input is 140 characters wide
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let side = size.unwrap_or(140);
+
+    lines((0..side).map(|_| word(rng, b"XMAS", side)))
+}
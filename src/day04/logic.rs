@@ -1,13 +1,14 @@
 use std::cmp::min;
 
+use crate::grid::Grid;
+
 static PATTERN: [char; 4] = ['X', 'M', 'A', 'S'];
 static ANTI_PATTERN: [char; 4] = ['S', 'A', 'M', 'X'];
 
 /// We assume the grid is a square
-#[allow(clippy::needless_range_loop)]
-pub fn solve_part_one(grid: &[Vec<char>]) -> u32 {
-    let width = grid[0].len();
-    let height = grid.len();
+pub fn solve_part_one(grid: &Grid<char>) -> u32 {
+    let width = grid.width();
+    let height = grid.height();
 
     // Count
     let mut count = 0;
@@ -17,7 +18,7 @@ pub fn solve_part_one(grid: &[Vec<char>]) -> u32 {
     let mut anti_pattern_pos;
 
     // Look horizontally
-    for line in grid {
+    for line in grid.rows() {
         pattern_pos = 0;
         anti_pattern_pos = 0;
         for item in line {
@@ -27,12 +28,12 @@ pub fn solve_part_one(grid: &[Vec<char>]) -> u32 {
     }
 
     // Look vertically
-    for column in 0..grid[0].len() {
+    for column in 0..width {
         pattern_pos = 0;
         anti_pattern_pos = 0;
-        for line in 0..grid.len() {
-            search_pattern(&PATTERN, grid[line][column], &mut pattern_pos, &mut count);
-            search_pattern(&ANTI_PATTERN, grid[line][column], &mut anti_pattern_pos, &mut count);
+        for item in grid.column(column) {
+            search_pattern(&PATTERN, *item, &mut pattern_pos, &mut count);
+            search_pattern(&ANTI_PATTERN, *item, &mut anti_pattern_pos, &mut count);
         }
     }
 
@@ -97,9 +98,9 @@ fn search_pattern(pattern: &'static [char; 4], item: char, pattern_pos: &mut usi
     }
 }
 
-pub fn solve_part_two(grid: &[Vec<char>]) -> u32 {
-    let width = grid[0].len();
-    let height = grid.len();
+pub fn solve_part_two(grid: &Grid<char>) -> u32 {
+    let width = grid.width();
+    let height = grid.height();
 
     // walkers
     let mut m_count;
@@ -1,29 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day04::generator::generate;
 use crate::day04::logic::{solve_part_one, solve_part_two};
 use crate::day04::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day04::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day04 {
-    parsed_data: Option<Vec<Vec<char>>>,
-}
+pub struct Day04;
 
 impl AdventSolution for Day04 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Grid<char>;
+    type Prepared = Grid<char>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(4))
+    }
+
+    fn serialize(grid: &Self::Parsed) -> String {
+        serialize(grid)
+    }
+
+    fn prepare(grid: Self::Parsed) -> Self::Prepared {
+        grid
+    }
+
+    fn solve_part_one(grid: &Self::Prepared) -> Answer {
+        part_one(grid).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(grid: &Self::Prepared) -> Answer {
+        part_two(grid).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the word search
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Day04::parse(input.to_string())
+}
+
+/// Write the word search in the input format
+pub fn serialize(grid: &Grid<char>) -> String {
+    serialize_input(grid)
+}
+
+/// Number of times `XMAS` appears
+pub fn part_one(grid: &Grid<char>) -> u32 {
+    solve_part_one(grid)
+}
+
+/// Number of `MAS` crosses
+pub fn part_two(grid: &Grid<char>) -> u32 {
+    solve_part_two(grid)
+}
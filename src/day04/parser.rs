@@ -1,3 +1,7 @@
-pub fn parse_input(input: String) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
+use crate::errors::ParseError;
+use crate::grid::Grid;
+use crate::parsing::char_grid;
+
+pub fn parse_input(input: String) -> Result<Grid<char>, ParseError> {
+    char_grid(&input)
 }
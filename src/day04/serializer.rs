@@ -0,0 +1,5 @@
+use crate::grid::Grid;
+
+pub fn serialize_input(grid: &Grid<char>) -> String {
+    grid.to_string()
+}
@@ -1,13 +1,18 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day04::Day04;
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day04");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day04::default(), INPUT_EXAMPLE, 18);
+    test_part_one_common::<Day04>(INPUT_EXAMPLE, 18);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day04::default(), INPUT_EXAMPLE, 9);
+    test_part_two_common::<Day04>(INPUT_EXAMPLE, 9);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day04>(4);
 }
@@ -0,0 +1,37 @@
+use itertools::Itertools;
+
+use crate::generate::{lines, Rng};
+
+/// Ordering rules between pages followed by updates, the real input has 49 pages and about 200
+/// updates.
+///
+/// Rules are given for every pair of pages so that every update can be ordered.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(200);
+
+    // Pages are two digits numbers, they are ordered as they appear in this list
+    let mut pages: Vec<_> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules: Vec<_> = pages
+        .iter()
+        .tuple_combinations()
+        .map(|(left, right)| format!("{left}|{right}"))
+        .collect();
+    rng.shuffle(&mut rules);
+
+    // Updates have an odd number of pages so that they have a middle page
+    let updates = (0..count).map(|_| {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(rng.range(2..12) * 2 + 1);
+        if rng.chance(0.5) {
+            // Half of the updates are already in the right order
+            update.sort_by_key(|page| pages.iter().position(|other| other == page));
+        }
+        update.iter().join(",")
+    });
+
+    lines(rules) + "\n" + &lines(updates)
+}
@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day05::generator::generate;
 use crate::day05::logic::{solve_part_one, solve_part_two};
-use crate::day05::models::ManualUpdates;
+pub use crate::day05::models::ManualUpdates;
 use crate::day05::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day05::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day05 {
-    parsed_data: Option<ManualUpdates>,
-}
+pub struct Day05;
 
 impl AdventSolution for Day05 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = ManualUpdates;
+    type Prepared = ManualUpdates;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(5))
+    }
+
+    fn serialize(manual_updates: &Self::Parsed) -> String {
+        serialize(manual_updates)
+    }
+
+    fn prepare(manual_updates: Self::Parsed) -> Self::Prepared {
+        manual_updates
+    }
+
+    fn solve_part_one(manual_updates: &Self::Prepared) -> Answer {
+        part_one(manual_updates).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(manual_updates: &Self::Prepared) -> Answer {
+        part_two(manual_updates).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the ordering rules and the updates
+pub fn parse(input: &str) -> Result<ManualUpdates, ParseError> {
+    Day05::parse(input.to_string())
+}
+
+/// Write the ordering rules and the updates in the input format
+pub fn serialize(manual_updates: &ManualUpdates) -> String {
+    serialize_input(manual_updates)
+}
+
+/// Sum of the middle pages of the correctly ordered updates
+pub fn part_one(manual_updates: &ManualUpdates) -> i32 {
+    solve_part_one(manual_updates)
+}
+
+/// Sum of the middle pages of the incorrectly ordered updates once ordered
+pub fn part_two(manual_updates: &ManualUpdates) -> i32 {
+    solve_part_two(manual_updates)
+}
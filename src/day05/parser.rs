@@ -1,43 +1,42 @@
 use hashbrown::{HashMap, HashSet};
 use nom::bytes::complete::tag;
-use nom::character::complete::{i32 as i32_parser, line_ending};
-use nom::combinator::map;
-use nom::multi::{many1, separated_list1};
-use nom::sequence::tuple;
+use nom::multi::many1;
+use nom::sequence::separated_pair;
 use nom::{IResult, Parser};
 
 use crate::day05::models::ManualUpdates;
+use crate::errors::ParseError;
+use crate::parsing::{blank_lines, finish, line, list, many1_complete, unsigned};
 
 type ParsedManualUpdates = (Vec<(i32, i32)>, Vec<Vec<i32>>);
 
 fn parse_rule(input: &str) -> IResult<&str, (i32, i32)> {
-    map(
-        tuple((i32_parser, tag("|"), i32_parser, line_ending)),
-        |(left, _, right, _)| (left, right),
-    )
-    .parse(input)
+    line(separated_pair(unsigned, tag("|"), unsigned)).parse(input)
 }
 
 fn parse_update(input: &str) -> IResult<&str, Vec<i32>> {
-    map(
-        tuple((separated_list1(tag(","), i32_parser), line_ending)),
-        |(list, _)| list,
-    )
-    .parse(input)
+    line(list(",", unsigned)).parse(input)
 }
 
 fn parse_manual_updates(input: &str) -> IResult<&str, ParsedManualUpdates> {
-    map(
-        tuple((many1(parse_rule), line_ending, many1(parse_update))),
-        |(rules, _, updates)| (rules, updates),
-    )
-    .parse(input)
+    separated_pair(many1(parse_rule), blank_lines, many1_complete(parse_update)).parse(input)
 }
 
-pub fn parse_input(input: String) -> ManualUpdates {
-    let (res, (rules_vec, updates)) = parse_manual_updates.parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
+pub fn parse_input(input: String) -> Result<ManualUpdates, ParseError> {
+    let (rules_vec, updates) = finish(&input, parse_manual_updates.parse(&input))?;
+
+    // Updates are the last lines of the input, each of them needs a middle page
+    let first_update = input.lines().count() - updates.len();
+    for (i, update) in updates.iter().enumerate() {
+        if update.len().is_multiple_of(2) {
+            let line = input.lines().nth(first_update + i).unwrap_or_default();
+            return Err(ParseError::at_line(
+                first_update + i,
+                0,
+                line,
+                "expected an odd number of pages, the update has no middle page",
+            ));
+        }
     }
 
     // Put rules in a hashmap for easier access later
@@ -46,5 +45,5 @@ pub fn parse_input(input: String) -> ManualUpdates {
         rules.entry(before).or_default().insert(after);
     }
 
-    ManualUpdates { rules, updates }
+    Ok(ManualUpdates { rules, updates })
 }
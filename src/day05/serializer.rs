@@ -0,0 +1,17 @@
+use itertools::Itertools;
+
+use crate::day05::models::ManualUpdates;
+use crate::generate::lines;
+
+pub fn serialize_input(manual_updates: &ManualUpdates) -> String {
+    // Rules are stored in a hashmap, sort them to get a stable output
+    let rules = manual_updates
+        .rules
+        .iter()
+        .flat_map(|(before, afters)| afters.iter().map(move |after| (*before, *after)))
+        .sorted()
+        .map(|(before, after)| format!("{before}|{after}"));
+    let updates = manual_updates.updates.iter().map(|update| update.iter().join(","));
+
+    lines(rules) + "\n" + &lines(updates)
+}
@@ -1,14 +1,25 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day05::Day05;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day05");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day05::default(), INPUT_EXAMPLE, 143);
+    test_part_one_common::<Day05>(INPUT_EXAMPLE, 143);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day05::default(), INPUT_EXAMPLE, 123);
+    test_part_two_common::<Day05>(INPUT_EXAMPLE, 123);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day05>("47|53\n\n75,47,61\n75,47\n", 4, 1);
+    test_parse_error_common::<Day05>("47|-53\n\n75,47,61\n", 1, 4);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day05>(5);
 }
@@ -0,0 +1,45 @@
+use crate::generate::{lines, Rng};
+use crate::grid::{Coordinates, Direction, Grid};
+
+/// Number of moves of the guard before leaving the lab, `None` if it walks in a loop
+fn guard_moves(lab: &Grid<char>, start: Coordinates) -> Option<usize> {
+    let mut visited = Grid::new(lab.width(), lab.height(), [false; 4]);
+    let mut position = start;
+    let mut direction = Direction::Up;
+    for moves in 0.. {
+        if visited[position][direction.index()] {
+            return None;
+        }
+        visited[position][direction.index()] = true;
+
+        match position.step(direction).filter(|next| lab.contains(*next)) {
+            None => return Some(moves),
+            Some(next) if lab[next] == '#' => direction = direction.turn_right(),
+            Some(next) => position = next,
+        }
+    }
+    unreachable!()
+}
+
+/// Lab with a few obstacles and a guard that walks a long way before leaving it, the real input is 130 tiles wide
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    // Smaller labs are too small for the guard to walk long enough
+    let side = size.unwrap_or(130).max(3);
+
+    // Try new labs until the guard does not walk in a loop, which the puzzle does not allow, and
+    // walks long enough to be interesting
+    loop {
+        let mut lab = Grid::new(side, side, '.');
+        for position in lab.coordinates().collect::<Vec<_>>() {
+            if rng.chance(0.05) {
+                lab[position] = '#';
+            }
+        }
+
+        let start = Coordinates::new(rng.range(0..side), rng.range(0..side));
+        lab[start] = '^';
+        if guard_moves(&lab, start).is_some_and(|moves| moves >= side * 2) {
+            return lines(lab.rows().map(|row| row.iter().collect()));
+        }
+    }
+}
@@ -1,28 +1,31 @@
-use crate::day06::models::{Direction, LabWithABorder, Position, Tile};
 use hashbrown::HashSet;
 
-type Lookup = Vec<Vec<[Option<usize>; 4]>>;
-type Changes = Vec<(Position, Direction, Option<usize>)>;
+use crate::day06::models::{LabWithABorder, Tile};
+use crate::grid::{Coordinates, Direction, Grid};
+use crate::logging::trace;
 
-pub fn prepare(lab: &LabWithABorder) -> Vec<Vec<bool>> {
+type Lookup = Grid<[Option<usize>; 4]>;
+type Changes = Vec<(Coordinates, Direction, Option<usize>)>;
+
+pub fn prepare(lab: &LabWithABorder) -> Grid<bool> {
     // Create a list of visited tiles
-    let mut visited = vec![vec![false; lab.width]; lab.height];
+    let mut visited = Grid::new(lab.grid.width(), lab.grid.height(), false);
 
     // Simulate the guard visit
-    let mut guard_position = lab.start_position.clone();
+    let mut guard_position = lab.start_position;
     let mut guard_direction = Direction::Up;
     loop {
         // Add the guard position to the visited list
-        visited[guard_position.y][guard_position.x] = true;
+        visited[guard_position] = true;
 
-        // Get the next position
-        let next = guard_position.next(guard_direction);
+        // Get the next position, the border ensures that we never leave the grid
+        let next = guard_position + guard_direction;
 
         // If we are outside, we are done.
         // If the next position is occupied, we only get the next direction.
-        match lab.grid[next.y][next.x] {
+        match lab.grid[next] {
             Tile::Floor => guard_position = next,
-            Tile::Wall => guard_direction = guard_direction.next(),
+            Tile::Wall => guard_direction = guard_direction.turn_right(),
             Tile::Outside => break,
         }
     }
@@ -30,30 +33,28 @@ pub fn prepare(lab: &LabWithABorder) -> Vec<Vec<bool>> {
     visited
 }
 
-pub fn solve_part_one(visited: &[Vec<bool>]) -> usize {
-    visited.iter().map(|line| line.iter().filter(|x| **x).count()).sum()
+pub fn solve_part_one(visited: &Grid<bool>) -> usize {
+    visited.find_all(&true).count()
 }
 
-pub fn solve_part_two(lab: &LabWithABorder, visited: &[Vec<bool>]) -> u32 {
+pub fn solve_part_two(lab: &LabWithABorder, visited: &Grid<bool>) -> u32 {
     let mut lookup = build_lookup_table(lab);
 
     let mut possible_loops = 0;
-    for (y, line) in visited.iter().enumerate() {
-        for (x, visited) in line.iter().enumerate() {
-            if *visited == true && (lab.start_position.x != x || lab.start_position.y != y) {
-                // The tile was visited and is not the starting tile
-
-                // Add the wall to the map
-                let changes = add_wall(&Position::new(x, y), &mut lookup, lab.width, lab.height);
+    for position in visited.find_all(&true) {
+        if position != lab.start_position {
+            // The tile was visited and is not the starting tile
 
-                // Check if the guard does a loop
-                if does_loop(&lab.start_position, Direction::Up, &lookup) {
-                    possible_loops += 1;
-                }
+            // Add the wall to the map
+            let changes = add_wall(&position, &mut lookup);
 
-                // Revert the add_wall operation
-                revert_changes(changes, &mut lookup);
+            // Check if the guard does a loop
+            if does_loop(&lab.start_position, Direction::Up, &lookup) {
+                possible_loops += 1;
             }
+
+            // Revert the add_wall operation
+            revert_changes(changes, &mut lookup);
         }
     }
 
@@ -61,18 +62,21 @@ pub fn solve_part_two(lab: &LabWithABorder, visited: &[Vec<bool>]) -> u32 {
 }
 
 /// Add a wall at the given position
-fn add_wall(position: &Position, lookup: &mut Lookup, width: usize, height: usize) -> Changes {
+fn add_wall(position: &Coordinates, lookup: &mut Lookup) -> Changes {
+    let width = lookup.width();
+    let height = lookup.height();
+
     // Store change to simplify reverting them after
     let mut original = vec![];
 
     // we start at the wall line, for every lookup at it's left that did not change from the
     // lookup that was present at the wall position, change it to reference this new lookup.
-    let right_index = Direction::Right.get_lookup_index();
-    let initial_lookup = lookup[position.y][position.x][right_index].clone();
+    let right_index = Direction::Right.index();
+    let initial_lookup = lookup[*position][right_index];
     for x in (0..position.x).rev() {
-        if &lookup[position.y][x][right_index] == &initial_lookup {
+        if lookup[position.y][x][right_index] == initial_lookup {
             // Store the initial value for later
-            original.push((Position::new(x, position.y), Direction::Right, initial_lookup.clone()));
+            original.push((Coordinates::new(x, position.y), Direction::Right, initial_lookup));
 
             // Change the value
             lookup[position.y][x][right_index] = Some(position.x - 1);
@@ -83,12 +87,12 @@ fn add_wall(position: &Position, lookup: &mut Lookup, width: usize, height: usiz
 
     // we start at the wall line, for every lookup at it's right that did not change from the
     // lookup that was present at the wall position, change it to reference this new lookup.
-    let left_index = Direction::Left.get_lookup_index();
-    let initial_lookup = lookup[position.y][position.x][left_index].clone();
+    let left_index = Direction::Left.index();
+    let initial_lookup = lookup[*position][left_index];
     for x in position.x..width {
-        if &lookup[position.y][x][left_index] == &initial_lookup {
+        if lookup[position.y][x][left_index] == initial_lookup {
             // Store the initial value for later
-            original.push((Position::new(x, position.y), Direction::Left, initial_lookup.clone()));
+            original.push((Coordinates::new(x, position.y), Direction::Left, initial_lookup));
 
             // Change the value
             lookup[position.y][x][left_index] = Some(position.x + 1);
@@ -99,12 +103,12 @@ fn add_wall(position: &Position, lookup: &mut Lookup, width: usize, height: usiz
 
     // we start at the wall column, for every lookup upper that did not change from the
     // lookup that was present at the wall position, change it to reference this new lookup.
-    let down_index = Direction::Down.get_lookup_index();
-    let initial_lookup = lookup[position.y][position.x][down_index].clone();
+    let down_index = Direction::Down.index();
+    let initial_lookup = lookup[*position][down_index];
     for y in (0..position.y).rev() {
-        if &lookup[y][position.x][down_index] == &initial_lookup {
+        if lookup[y][position.x][down_index] == initial_lookup {
             // Store the initial value for later
-            original.push((Position::new(position.x, y), Direction::Down, initial_lookup.clone()));
+            original.push((Coordinates::new(position.x, y), Direction::Down, initial_lookup));
 
             // Change the value
             lookup[y][position.x][down_index] = Some(position.y - 1);
@@ -115,12 +119,12 @@ fn add_wall(position: &Position, lookup: &mut Lookup, width: usize, height: usiz
 
     // we start at the wall column, for every lookup lower that did not change from the
     // lookup that was present at the wall position, change it to reference this new lookup.
-    let up_index = Direction::Up.get_lookup_index();
-    let initial_lookup = lookup[position.y][position.x][up_index].clone();
+    let up_index = Direction::Up.index();
+    let initial_lookup = lookup[*position][up_index];
     for y in position.y..height {
-        if &lookup[y][position.x][up_index] == &initial_lookup {
+        if lookup[y][position.x][up_index] == initial_lookup {
             // Store the initial value for later
-            original.push((Position::new(position.x, y), Direction::Up, initial_lookup.clone()));
+            original.push((Coordinates::new(position.x, y), Direction::Up, initial_lookup));
 
             // Change the value
             lookup[y][position.x][up_index] = Some(position.y + 1);
@@ -132,27 +136,26 @@ fn add_wall(position: &Position, lookup: &mut Lookup, width: usize, height: usiz
     original
 }
 
-
 /// Revert the last add_wall operation
 fn revert_changes(changes: Changes, lookup: &mut Lookup) {
     for (position, direction, value) in changes {
-        lookup[position.y][position.x][direction.get_lookup_index()] = value;
+        lookup[position][direction.index()] = value;
     }
 }
 
-fn does_loop(position: &Position, direction: Direction, lookup: &Lookup) -> bool {
+fn does_loop(position: &Coordinates, direction: Direction, lookup: &Lookup) -> bool {
     // Store visited vertical positions
     let mut visited = HashSet::new();
 
     // Set mutable variables for the position and the direction
-    let mut position = position.clone();
-    let mut direction = direction.clone();
+    let mut position = *position;
+    let mut direction = direction;
 
     loop {
-        // println!("{}=>{}", &position, &direction);
+        trace!("{}=>{}", &position, &direction);
 
         // Teleport the guard to the next position
-        if let Some(next_position) = lookup[position.y][position.x][direction.get_lookup_index()] {
+        if let Some(next_position) = lookup[position][direction.index()] {
             // Teleport the guard depending on the direction
             match direction {
                 Direction::Up | Direction::Down => position.y = next_position,
@@ -163,33 +166,36 @@ fn does_loop(position: &Position, direction: Direction, lookup: &Lookup) -> bool
             if direction == Direction::Up {
                 // Stopping condition
                 if visited.contains(&position) {
-                    // println!("{:#?}", &visited);
+                    trace!("loop found, visited: {:?}", &visited);
                     return true;
                 }
 
-                visited.insert(position.clone());
+                visited.insert(position);
             }
 
             // Rotate
-            direction = direction.next();
+            direction = direction.turn_right();
         } else {
             // The guard is going OOB, this cannot be a loop
-            // println!("{:#?}", &visited);
+            trace!("no loop, visited: {:?}", &visited);
             return false;
         }
     }
 }
 
 fn build_lookup_table(lab: &LabWithABorder) -> Lookup {
+    let width = lab.grid.width();
+    let height = lab.grid.height();
+
     // Create lookup tables for every direction that allows us to tp the guard to the next
     // obstacle in constant time
-    let mut lookup = vec![vec![[None, None, None, None]; lab.width]; lab.height];
+    let mut lookup = Grid::new(width, height, [None, None, None, None]);
 
     // Fill the up lookup, to do that, we iterate from top to bottom on every column
-    let up_index = Direction::Up.get_lookup_index();
-    for x in 0..lab.width {
+    let up_index = Direction::Up.index();
+    for x in 0..width {
         let mut last_wall = None;
-        for y in 0..lab.height {
+        for y in 0..height {
             if lab.grid[y][x] == Tile::Wall {
                 // Update the last wall
                 last_wall = Some(y + 1);
@@ -201,10 +207,10 @@ fn build_lookup_table(lab: &LabWithABorder) -> Lookup {
     }
 
     // Fill the right lookup, to do that, we iterate from right to left on every line
-    let right_index = Direction::Right.get_lookup_index();
-    for y in 0..lab.height {
+    let right_index = Direction::Right.index();
+    for y in 0..height {
         let mut last_wall = None;
-        for x in (0..lab.width).rev() {
+        for x in (0..width).rev() {
             if lab.grid[y][x] == Tile::Wall {
                 // Update the last wall
                 last_wall = Some(x - 1);
@@ -216,10 +222,10 @@ fn build_lookup_table(lab: &LabWithABorder) -> Lookup {
     }
 
     // Fill the down lookup, to do that, we iterate from bottom to top on every column
-    let down_index = Direction::Down.get_lookup_index();
-    for x in 0..lab.width {
+    let down_index = Direction::Down.index();
+    for x in 0..width {
         let mut last_wall = None;
-        for y in (0..lab.height).rev() {
+        for y in (0..height).rev() {
             if lab.grid[y][x] == Tile::Wall {
                 // Update the last wall
                 last_wall = Some(y - 1);
@@ -231,8 +237,8 @@ fn build_lookup_table(lab: &LabWithABorder) -> Lookup {
     }
 
     // Fill the left lookup, to do that, we iterate from left to right on every line
-    let left_index = Direction::Left.get_lookup_index();
-    for (y, line) in lab.grid.iter().enumerate() {
+    let left_index = Direction::Left.index();
+    for (y, line) in lab.grid.rows().enumerate() {
         let mut last_wall = None;
         for (x, tile) in line.iter().enumerate() {
             if tile == &Tile::Wall {
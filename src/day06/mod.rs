@@ -1,35 +1,91 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod reference;
+mod serializer;
+mod visualization;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day06::generator::generate;
 use crate::day06::logic::{prepare, solve_part_one, solve_part_two};
-use crate::day06::models::LabWithABorder;
+pub use crate::day06::models::LabWithABorder;
 use crate::day06::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day06::reference::{reference_part_one, reference_part_two};
+use crate::day06::serializer::serialize_input;
+use crate::day06::visualization::{frames, picture};
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::Color;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day06 {
-    parsed_data: Option<LabWithABorder>,
-    prepared_data: Option<Vec<Vec<bool>>>,
-}
+pub struct Day06;
 
 impl AdventSolution for Day06 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = LabWithABorder;
+    type Prepared = (LabWithABorder, Grid<bool>);
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(6))
+    }
+
+    fn serialize(lab: &Self::Parsed) -> String {
+        serialize(lab)
+    }
+
+    fn prepare(lab: Self::Parsed) -> Self::Prepared {
+        let visited = prepare(&lab);
+        (lab, visited)
+    }
+
+    fn solve_part_one((_, visited): &Self::Prepared) -> Answer {
+        solve_part_one(visited).into()
+    }
+
+    fn solve_part_two((lab, visited): &Self::Prepared) -> Answer {
+        solve_part_two(lab, visited).into()
+    }
+
+    fn reference_part_one(lab: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_one(lab).into())
+    }
+
+    fn reference_part_two(lab: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_two(lab).into())
     }
 
-    fn prepare(&mut self) {
-        self.prepared_data = Some(prepare(self.parsed_data.as_ref().unwrap()));
+    fn visualize(lab: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(frames(lab)))
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.prepared_data.as_ref().unwrap()) as i128
+    fn export(lab: &Self::Parsed, step: Option<usize>) -> Option<Grid<Color>> {
+        Some(picture(lab, step))
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap(), self.prepared_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the lab map
+pub fn parse(input: &str) -> Result<LabWithABorder, ParseError> {
+    Day06::parse(input.to_string())
+}
+
+/// Write the lab map in the input format
+pub fn serialize(lab: &LabWithABorder) -> String {
+    serialize_input(lab)
+}
+
+/// Number of positions visited by the guard
+pub fn part_one(lab: &LabWithABorder) -> usize {
+    solve_part_one(&prepare(lab))
+}
+
+/// Number of positions where a new obstruction traps the guard in a loop
+pub fn part_two(lab: &LabWithABorder) -> u32 {
+    solve_part_two(lab, &prepare(lab))
+}
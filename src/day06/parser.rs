@@ -1,26 +1,28 @@
-use crate::day06::models::{LabWithABorder, Position, Tile};
+use crate::day06::models::{LabWithABorder, Tile};
+use crate::errors::ParseError;
+use crate::grid::Grid;
 
-pub fn parse_input(input: String) -> LabWithABorder {
-    // We won't need to add a border here since the map already has one.
-    let mut grid: Vec<Vec<Tile>> = vec![];
-    let mut start_position = Position::default();
+pub fn parse_input(input: String) -> Result<LabWithABorder, ParseError> {
+    let mut start_position = None;
 
-    for (y, line) in input.lines().enumerate() {
-        grid.push(
-            line.chars()
-                .enumerate()
-                .map(|(x, tile)| match tile {
-                    '#' => Tile::Wall,
-                    '.' => Tile::Floor,
-                    '^' => {
-                        start_position = Position::new(x, y);
-                        Tile::Floor
-                    }
-                    _ => unreachable!(),
-                })
-                .collect(),
-        )
-    }
+    let grid = Grid::parse(&input, |position, tile| match tile {
+        '#' => Ok(Tile::Wall),
+        '.' => Ok(Tile::Floor),
+        '^' => {
+            start_position = Some(position);
+            Ok(Tile::Floor)
+        }
+        _ => Err(format!("unknown tile '{tile}'")),
+    })?;
 
-    LabWithABorder::new(grid, start_position)
+    let Some(start_position) = start_position else {
+        return Err(ParseError::at_line(
+            0,
+            0,
+            input.lines().next().unwrap_or_default(),
+            "missing guard '^'",
+        ));
+    };
+
+    Ok(LabWithABorder::new(grid, start_position))
 }
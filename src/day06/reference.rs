@@ -0,0 +1,35 @@
+use hashbrown::HashSet;
+
+use crate::day06::models::{LabWithABorder, Tile};
+use crate::grid::{Coordinates, Direction};
+
+/// Walk the guard step by step, returns the visited tiles or `None` if the guard walks in a loop
+fn walk(lab: &LabWithABorder, obstacle: Option<Coordinates>) -> Option<HashSet<Coordinates>> {
+    let mut states = HashSet::new();
+    let mut position = lab.start_position;
+    let mut direction = Direction::Up;
+
+    while states.insert((position, direction)) {
+        let next = position + direction;
+        match lab.grid[next] {
+            Tile::Outside => return Some(states.into_iter().map(|(position, _)| position).collect()),
+            Tile::Wall => direction = direction.turn_right(),
+            Tile::Floor if Some(next) == obstacle => direction = direction.turn_right(),
+            Tile::Floor => position = next,
+        }
+    }
+
+    None
+}
+
+pub fn reference_part_one(lab: &LabWithABorder) -> usize {
+    walk(lab, None).map_or(0, |visited| visited.len())
+}
+
+/// Try an obstacle on every floor tile
+pub fn reference_part_two(lab: &LabWithABorder) -> usize {
+    lab.grid
+        .find_all(&Tile::Floor)
+        .filter(|position| *position != lab.start_position && walk(lab, Some(*position)).is_none())
+        .count()
+}
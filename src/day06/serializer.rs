@@ -0,0 +1,8 @@
+use crate::day06::models::LabWithABorder;
+
+pub fn serialize_input(lab: &LabWithABorder) -> String {
+    // Remove the border added by the parser
+    let mut grid = lab.grid.unpadded(1).map(|tile| tile.to_string());
+    grid[lab.start_position.offset(-1, -1).unwrap()] = "^".to_string();
+    grid.to_string()
+}
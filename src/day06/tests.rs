@@ -1,14 +1,19 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day06::Day06;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day06");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day06::default(), INPUT_EXAMPLE, 41);
+    test_part_one_common::<Day06>(INPUT_EXAMPLE, 41);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day06::default(), INPUT_EXAMPLE, 6);
+    test_part_two_common::<Day06>(INPUT_EXAMPLE, 6);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day06>(6);
 }
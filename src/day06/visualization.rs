@@ -0,0 +1,84 @@
+use colored::Colorize;
+
+use crate::day06::models::{LabWithABorder, Tile};
+use crate::grid::{Direction, Grid};
+use crate::image::Color;
+use crate::visualize::render_grid;
+
+/// Show the guard walking through the lab, one frame per step
+pub fn frames(lab: &LabWithABorder) -> impl Iterator<Item = String> + '_ {
+    let mut visited = Grid::new(lab.grid.width(), lab.grid.height(), false);
+    let mut guard = Some((lab.start_position, Direction::Up));
+
+    std::iter::from_fn(move || {
+        let (position, direction) = guard?;
+        visited[position] = true;
+
+        let frame = render_grid(&lab.grid, |current, tile| {
+            if current == position {
+                match direction {
+                    Direction::Up => "^",
+                    Direction::Right => ">",
+                    Direction::Down => "v",
+                    Direction::Left => "<",
+                }
+                .red()
+                .bold()
+            } else if visited[current] {
+                "X".yellow()
+            } else {
+                match tile {
+                    Tile::Floor => ".".dimmed(),
+                    Tile::Wall => "#".bold(),
+                    Tile::Outside => " ".normal(),
+                }
+            }
+        });
+
+        // Move the guard, the simulation stops once it left the lab
+        let next = position + direction;
+        guard = match lab.grid[next] {
+            Tile::Floor => Some((next, direction)),
+            Tile::Wall => Some((position, direction.turn_right())),
+            Tile::Outside => None,
+        };
+
+        Some(frame)
+    })
+}
+
+/// Picture of the tiles visited by the guard after `step` moves, or until it leaves the lab
+pub fn picture(lab: &LabWithABorder, step: Option<usize>) -> Grid<Color> {
+    let mut visited = Grid::new(lab.grid.width(), lab.grid.height(), false);
+    let mut guard = Some((lab.start_position, Direction::Up));
+
+    let mut moves = 0;
+    while let Some((position, direction)) = guard {
+        visited[position] = true;
+        if step.is_some_and(|step| moves >= step) {
+            break;
+        }
+
+        let next = position + direction;
+        guard = match lab.grid[next] {
+            Tile::Floor => Some((next, direction)),
+            Tile::Wall => Some((position, direction.turn_right())),
+            Tile::Outside => None,
+        };
+        moves += 1;
+    }
+
+    let mut image = lab.grid.map(|tile| match tile {
+        Tile::Floor => Color::DARK_GREY,
+        Tile::Wall => Color::GREY,
+        Tile::Outside => Color::BLACK,
+    });
+    for position in visited.find_all(&true) {
+        image[position] = Color::YELLOW;
+    }
+    if let Some((position, _)) = guard {
+        image[position] = Color::RED;
+    }
+
+    image
+}
@@ -0,0 +1,28 @@
+use itertools::Itertools;
+
+use crate::generate::{lines, Rng};
+
+/// Calibration equations, about half of them can be made true, the real input has 850 of them
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(850);
+
+    lines((0..count).map(|_| loop {
+        let operands: Vec<u64> = (0..rng.range(2..13)).map(|_| rng.range(1..1000) as u64).collect();
+
+        // Combine the operands with random operators, retry if the result is too big
+        let result = operands[1..]
+            .iter()
+            .try_fold(operands[0], |current, operand| match rng.range(0..3) {
+                0 => current.checked_add(*operand),
+                1 => current.checked_mul(*operand),
+                _ => format!("{current}{operand}").parse().ok(),
+            });
+        let Some(result) = result.filter(|result| *result < 10u64.pow(15)) else {
+            continue;
+        };
+
+        // Break some equations
+        let result = if rng.chance(0.5) { result + 1 } else { result };
+        break format!("{result}: {}", operands.iter().join(" "));
+    }))
+}
@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day07::generator::generate;
 use crate::day07::logic::{solve_part_one, solve_part_two};
-use crate::day07::models::Equation;
+pub use crate::day07::models::Equation;
 use crate::day07::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day07::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day07 {
-    parsed_data: Option<Vec<Equation>>,
-}
+pub struct Day07;
 
 impl AdventSolution for Day07 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<Equation>;
+    type Prepared = Vec<Equation>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(7))
+    }
+
+    fn serialize(equations: &Self::Parsed) -> String {
+        serialize(equations)
+    }
+
+    fn prepare(equations: Self::Parsed) -> Self::Prepared {
+        equations
+    }
+
+    fn solve_part_one(equations: &Self::Prepared) -> Answer {
+        part_one(equations).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(equations: &Self::Prepared) -> Answer {
+        part_two(equations).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the calibration equations
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    Day07::parse(input.to_string())
+}
+
+/// Write the calibration equations in the input format
+pub fn serialize(equations: &[Equation]) -> String {
+    serialize_input(equations)
+}
+
+/// Total calibration of the equations that can be solved with `+` and `*`
+pub fn part_one(equations: &[Equation]) -> u128 {
+    solve_part_one(equations)
+}
+
+/// Total calibration of the equations that can be solved with `+`, `*` and `||`
+pub fn part_two(equations: &[Equation]) -> u128 {
+    solve_part_two(equations)
+}
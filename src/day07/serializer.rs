@@ -0,0 +1,12 @@
+use itertools::Itertools;
+
+use crate::day07::models::Equation;
+use crate::generate::lines;
+
+pub fn serialize_input(equations: &[Equation]) -> String {
+    lines(
+        equations
+            .iter()
+            .map(|equation| format!("{}: {}", equation.result, equation.operands.iter().join(" "))),
+    )
+}
@@ -1,14 +1,19 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day07::Day07;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day07");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day07::default(), INPUT_EXAMPLE, 3749);
+    test_part_one_common::<Day07>(INPUT_EXAMPLE, 3749);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day07::default(), INPUT_EXAMPLE, 11387);
+    test_part_two_common::<Day07>(INPUT_EXAMPLE, 11387);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day07>(7);
 }
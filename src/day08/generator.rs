@@ -0,0 +1,18 @@
+use crate::generate::{lines, Rng};
+use crate::grid::Grid;
+
+/// Frequencies of the antennas
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Map of antennas, a few antennas for each frequency, the real input is 50 tiles wide
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let side = size.unwrap_or(50);
+
+    let mut map = Grid::new(side, side, '.');
+    let positions: Vec<_> = map.coordinates().collect();
+    for _ in 0..side * side / 14 {
+        map[*rng.choose(&positions)] = *rng.choose(FREQUENCIES) as char;
+    }
+
+    lines(map.rows().map(|row| row.iter().collect()))
+}
@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day08::generator::generate;
 use crate::day08::logic::{solve_part_one, solve_part_two};
-use crate::day08::models::Map;
+pub use crate::day08::models::Map;
 use crate::day08::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day08::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day08 {
-    parsed_data: Option<Map>,
-}
+pub struct Day08;
 
 impl AdventSolution for Day08 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Map;
+    type Prepared = Map;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(8))
+    }
+
+    fn serialize(map: &Self::Parsed) -> String {
+        serialize(map)
+    }
+
+    fn prepare(map: Self::Parsed) -> Self::Prepared {
+        map
+    }
+
+    fn solve_part_one(map: &Self::Prepared) -> Answer {
+        part_one(map).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(map: &Self::Prepared) -> Answer {
+        part_two(map).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the antennas map
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Day08::parse(input.to_string())
+}
+
+/// Write the antennas map in the input format
+pub fn serialize(map: &Map) -> String {
+    serialize_input(map)
+}
+
+/// Number of locations containing an antinode
+pub fn part_one(map: &Map) -> usize {
+    solve_part_one(map)
+}
+
+/// Number of locations containing an antinode, resonant harmonics included
+pub fn part_two(map: &Map) -> usize {
+    solve_part_two(map)
+}
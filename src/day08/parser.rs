@@ -1,28 +1,35 @@
 use std::collections::HashMap;
 
 use crate::day08::models::{Antenna, Map};
+use crate::errors::ParseError;
+use crate::grid::Grid;
 
-pub fn parse_input(input: String) -> Map {
+pub fn parse_input(input: String) -> Result<Map, ParseError> {
     // Parse the map
-    let map: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
+    let map = Grid::parse(&input, |_, c| {
+        // Antennas are letters or digits
+        if c == '.' || c.is_ascii_alphanumeric() {
+            Ok(c)
+        } else {
+            Err(format!("unknown tile '{c}'"))
+        }
+    })?;
 
     // Retrieve antennas
     let mut antennas: HashMap<char, Vec<Antenna>> = HashMap::new();
-    for (y, line) in map.iter().enumerate() {
-        for (x, frequency) in line.iter().enumerate() {
-            if *frequency != '.' {
-                antennas.entry(*frequency).or_default().push(Antenna {
-                    frequency: *frequency,
-                    x: x as i32,
-                    y: y as i32,
-                })
-            }
+    for (position, frequency) in map.iter() {
+        if *frequency != '.' {
+            antennas.entry(*frequency).or_default().push(Antenna {
+                frequency: *frequency,
+                x: position.x as i32,
+                y: position.y as i32,
+            })
         }
     }
 
-    Map {
-        width: map[0].len() as i32,
-        height: map.len() as i32,
+    Ok(Map {
+        width: map.width() as i32,
+        height: map.height() as i32,
         antennas,
-    }
+    })
 }
@@ -0,0 +1,10 @@
+use crate::day08::models::Map;
+use crate::grid::{Coordinates, Grid};
+
+pub fn serialize_input(map: &Map) -> String {
+    let mut grid = Grid::new(map.width as usize, map.height as usize, '.');
+    for antenna in map.antennas.values().flatten() {
+        grid[Coordinates::new(antenna.x as usize, antenna.y as usize)] = antenna.frequency;
+    }
+    grid.to_string()
+}
@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day08::Day08;
 
 static INPUT_EXAMPLE: &str = "............
@@ -17,10 +17,23 @@ static INPUT_EXAMPLE: &str = "............
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day08::default(), INPUT_EXAMPLE, 14);
+    test_part_one_common::<Day08>(INPUT_EXAMPLE, 14);
+
+    // An empty line at the end of the input is ignored
+    test_part_one_common::<Day08>(&format!("{INPUT_EXAMPLE}\n"), 14);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day08::default(), INPUT_EXAMPLE, 34);
+    test_part_two_common::<Day08>(INPUT_EXAMPLE, 34);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day08>("..a.\n.#..\n", 2, 2);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day08>(8);
 }
@@ -0,0 +1,15 @@
+use crate::generate::Rng;
+
+/// Disk map alternating files and free spaces, the real input has 10000 files
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let files = size.unwrap_or(10000).max(1);
+
+    let mut disk_map: String = (0..files)
+        .flat_map(|_| [rng.range(1..10), rng.range(0..10)])
+        .map(|digit| char::from(b'0' + digit as u8))
+        .collect();
+
+    // The disk map ends with a file
+    disk_map.pop();
+    disk_map + "\n"
+}
@@ -1,29 +1,67 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day09::generator::generate;
 use crate::day09::logic::{solve_part_one, solve_part_two};
 use crate::day09::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day09::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day09 {
-    parsed_data: Vec<usize>,
-}
+pub struct Day09;
 
 impl AdventSolution for Day09 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = parse_input(_data);
+    type Parsed = Vec<usize>;
+    type Prepared = Vec<usize>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(9))
+    }
+
+    fn serialize(disk_map: &Self::Parsed) -> String {
+        serialize(disk_map)
+    }
+
+    fn prepare(disk_map: Self::Parsed) -> Self::Prepared {
+        disk_map
+    }
+
+    fn solve_part_one(disk_map: &Self::Prepared) -> Answer {
+        part_one(disk_map).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(&self.parsed_data) as i128
+    fn solve_part_two(disk_map: &Self::Prepared) -> Answer {
+        part_two(disk_map).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(&self.parsed_data) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the disk map
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    Day09::parse(input.to_string())
+}
+
+/// Write the disk map in the input format
+pub fn serialize(disk_map: &[usize]) -> String {
+    serialize_input(disk_map)
+}
+
+/// Filesystem checksum after moving the blocks one at a time
+pub fn part_one(disk_map: &[usize]) -> usize {
+    solve_part_one(disk_map)
+}
+
+/// Filesystem checksum after moving whole files
+pub fn part_two(disk_map: &[usize]) -> usize {
+    solve_part_two(disk_map)
+}
@@ -1,9 +1,16 @@
-pub fn parse_input(input: String) -> Vec<usize> {
-    input
-        .lines()
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as usize)
+use crate::errors::ParseError;
+
+pub fn parse_input(input: String) -> Result<Vec<usize>, ParseError> {
+    let Some(line) = input.lines().next().filter(|line| !line.is_empty()) else {
+        return Err(ParseError::at_line(0, 0, "", "expected a disk map"));
+    };
+
+    line.chars()
+        .enumerate()
+        .map(|(x, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| ParseError::at_line(0, x, line, "expected a digit"))
+        })
         .collect()
 }
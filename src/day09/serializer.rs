@@ -0,0 +1,3 @@
+pub fn serialize_input(disk_map: &[usize]) -> String {
+    disk_map.iter().map(|size| size.to_string()).collect::<String>() + "\n"
+}
@@ -1,14 +1,19 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day09::Day09;
 
 static INPUT_EXAMPLE: &str = "2333133121414131402";
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day09::default(), INPUT_EXAMPLE, 1928);
+    test_part_one_common::<Day09>(INPUT_EXAMPLE, 1928);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day09::default(), INPUT_EXAMPLE, 2858);
+    test_part_two_common::<Day09>(INPUT_EXAMPLE, 2858);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day09>(9);
 }
@@ -0,0 +1,36 @@
+use crate::generate::{lines, Rng};
+use crate::grid::{Coordinates, Grid};
+
+/// Topographic map with hiking trails going from 0 to 9, the real input is 50 tiles wide
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let side = size.unwrap_or(50).max(2);
+
+    let mut map = Grid::new(side, side, 0);
+    for position in map.coordinates().collect::<Vec<_>>() {
+        map[position] = rng.range(0..10);
+    }
+
+    // Draw random trails on the noise
+    for _ in 0..side * side / 10 {
+        let mut trail = vec![Coordinates::new(rng.range(0..side), rng.range(0..side))];
+        while trail.len() < 10 {
+            // A trail never goes back on itself, it is abandoned if it is stuck
+            let neighbours: Vec<_> = map
+                .neighbours(trail[trail.len() - 1])
+                .filter(|next| !trail.contains(next))
+                .collect();
+            if neighbours.is_empty() {
+                break;
+            }
+            trail.push(*rng.choose(&neighbours));
+        }
+        for (height, position) in trail.into_iter().enumerate() {
+            map[position] = height;
+        }
+    }
+
+    lines(
+        map.rows()
+            .map(|row| row.iter().map(|height| char::from(b'0' + *height as u8)).collect()),
+    )
+}
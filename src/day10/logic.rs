@@ -1,129 +1,90 @@
 use std::collections::HashSet;
 
-use crate::day10::models::MapWithBorder;
+use crate::grid::{Coordinates, Grid};
 
-type CacheEntry = Vec<Vec<Option<HashSet<(usize, usize)>>>>;
+type CacheEntry = Grid<Option<HashSet<Coordinates>>>;
 
-fn compute_trail_score_part_one(map: &MapWithBorder, cache: &mut CacheEntry, x: usize, y: usize) -> usize {
+fn compute_trail_score_part_one(map: &Grid<u32>, cache: &mut CacheEntry, position: Coordinates) -> usize {
     // First check if we have a result in the cache
-    if let Some(cached_value) = &cache[y][x] {
+    if let Some(cached_value) = &cache[position] {
         return cached_value.len();
     }
 
     // Get the map current value
-    let value = map.grid[y][x];
+    let value = map[position];
 
     // If we are on a 9, we are done, set the cache and return 1
     if value == 9 {
-        cache[y][x] = Some([(x, y)].into_iter().collect());
+        cache[position] = Some([position].into_iter().collect());
         return 1;
     };
 
     // Get the result from every direction that respect the hiking condition
     let mut end_set = HashSet::new();
-    // look up
-    if map.grid[y - 1][x] == value + 1 {
-        compute_trail_score_part_one(map, cache, x, y - 1);
-        // We know that the hashset for the neighbor is now defined by construction of the algorithm
-        end_set = end_set.union(cache[y - 1][x].as_ref().unwrap()).cloned().collect();
-    }
-    // Look right
-    if map.grid[y][x + 1] == value + 1 {
-        compute_trail_score_part_one(map, cache, x + 1, y);
-        // We know that the hashset for the neighbor is now defined by construction of the algorithm
-        end_set = end_set.union(cache[y][x + 1].as_ref().unwrap()).cloned().collect();
-    }
-    // Look down
-    if map.grid[y + 1][x] == value + 1 {
-        compute_trail_score_part_one(map, cache, x, y + 1);
-        // We know that the hashset for the neighbor is now defined by construction of the algorithm
-        end_set = end_set.union(cache[y + 1][x].as_ref().unwrap()).cloned().collect();
-    }
-    // Look left
-    if map.grid[y][x - 1] == value + 1 {
-        compute_trail_score_part_one(map, cache, x - 1, y);
-        // We know that the hashset for the neighbor is now defined by construction of the algorithm
-        end_set = end_set.union(cache[y][x - 1].as_ref().unwrap()).cloned().collect();
+    for neighbour in map.neighbours(position) {
+        if map[neighbour] == value + 1 {
+            compute_trail_score_part_one(map, cache, neighbour);
+            // We know that the hashset for the neighbor is now defined by construction of the algorithm
+            end_set = end_set.union(cache[neighbour].as_ref().unwrap()).cloned().collect();
+        }
     }
 
     // Set the cache with the result
     let result = end_set.len();
-    cache[y][x] = Some(end_set);
+    cache[position] = Some(end_set);
 
     // Return the result
     result
 }
 
-pub fn solve_part_one(map: &MapWithBorder) -> usize {
+pub fn solve_part_one(map: &Grid<u32>) -> usize {
     // Create a cache for the parkour
-    let mut cache = vec![vec![None; map.width]; map.height];
-
-    // Iterate over the inner map to compute the total score
-    let mut result = 0;
-    for y in 1..(map.width - 1) {
-        for x in 1..(map.height - 1) {
-            if map.grid[y][x] == 0 {
-                result += compute_trail_score_part_one(map, &mut cache, x, y);
-            }
-        }
-    }
+    let mut cache = Grid::new(map.width(), map.height(), None);
 
-    result
+    // Iterate over the map to compute the total score
+    map.find_all(&0)
+        .map(|trailhead| compute_trail_score_part_one(map, &mut cache, trailhead))
+        .sum()
 }
 
-fn compute_trail_score_part_two(map: &MapWithBorder, cache: &mut Vec<Vec<u32>>, x: usize, y: usize) -> u32 {
+fn compute_trail_score_part_two(map: &Grid<u32>, cache: &mut Grid<u32>, position: Coordinates) -> u32 {
     // First check if we have a result in the cache
-    if cache[y][x] != u32::MAX {
-        return cache[y][x];
+    if cache[position] != u32::MAX {
+        return cache[position];
     }
 
     // Get the map current value
-    let value = map.grid[y][x];
+    let value = map[position];
 
     // If we are on a 9, we are done, set the cache and return 1
     if value == 9 {
-        cache[y][x] = 1;
+        cache[position] = 1;
         return 1;
     };
 
     // Get the result from every direction that respect the hiking condition
     let mut result = 0;
-    // look up
-    if map.grid[y - 1][x] == value + 1 {
-        result += compute_trail_score_part_two(map, cache, x, y - 1);
-    }
-    // Look right
-    if map.grid[y][x + 1] == value + 1 {
-        result += compute_trail_score_part_two(map, cache, x + 1, y);
-    }
-    // Look down
-    if map.grid[y + 1][x] == value + 1 {
-        result += compute_trail_score_part_two(map, cache, x, y + 1);
-    }
-    // Look left
-    if map.grid[y][x - 1] == value + 1 {
-        result += compute_trail_score_part_two(map, cache, x - 1, y);
+    for neighbour in map.neighbours(position) {
+        if map[neighbour] == value + 1 {
+            result += compute_trail_score_part_two(map, cache, neighbour);
+        }
     }
 
     // Set the cache with the result
-    cache[y][x] = result;
+    cache[position] = result;
 
     result
 }
 
-pub fn solve_part_two(map: &MapWithBorder) -> usize {
+pub fn solve_part_two(map: &Grid<u32>) -> usize {
     // Create a cache for the parkour
-    let mut cache = vec![vec![u32::MAX; map.width]; map.height];
+    let mut cache = Grid::new(map.width(), map.height(), u32::MAX);
 
-    // Iterate over the inner map to compute the total score
-    let mut result = 0;
-    for y in 1..(map.width - 1) {
-        for x in 1..(map.height - 1) {
-            if map.grid[y][x] == 0 {
-                result += compute_trail_score_part_two(map, &mut cache, x, y);
-            }
-        }
-    }
+    // Iterate over the map to compute the total score
+    let result: u32 = map
+        .find_all(&0)
+        .map(|trailhead| compute_trail_score_part_two(map, &mut cache, trailhead))
+        .sum();
 
     result as usize
 }
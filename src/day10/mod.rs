@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day10::generator::generate;
 use crate::day10::logic::{solve_part_one, solve_part_two};
-use crate::day10::models::MapWithBorder;
 use crate::day10::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day10::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day10 {
-    parsed_data: Option<MapWithBorder>,
-}
+pub struct Day10;
 
 impl AdventSolution for Day10 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Grid<u32>;
+    type Prepared = Grid<u32>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(10))
+    }
+
+    fn serialize(map: &Self::Parsed) -> String {
+        serialize(map)
+    }
+
+    fn prepare(map: Self::Parsed) -> Self::Prepared {
+        map
+    }
+
+    fn solve_part_one(map: &Self::Prepared) -> Answer {
+        part_one(map).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(map: &Self::Prepared) -> Answer {
+        part_two(map).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the topographic map
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Day10::parse(input.to_string())
+}
+
+/// Write the topographic map in the input format
+pub fn serialize(map: &Grid<u32>) -> String {
+    serialize_input(map)
+}
+
+/// Sum of the scores of the trailheads
+pub fn part_one(map: &Grid<u32>) -> usize {
+    solve_part_one(map)
+}
+
+/// Sum of the ratings of the trailheads
+pub fn part_two(map: &Grid<u32>) -> usize {
+    solve_part_two(map)
+}
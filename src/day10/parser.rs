@@ -1,10 +1,7 @@
-use crate::day10::models::MapWithBorder;
+use crate::errors::ParseError;
+use crate::grid::Grid;
+use crate::parsing::digit_grid;
 
-pub fn parse_input(input: String) -> MapWithBorder {
-    MapWithBorder::new(
-        input
-            .lines()
-            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-            .collect(),
-    )
+pub fn parse_input(input: String) -> Result<Grid<u32>, ParseError> {
+    digit_grid(&input)
 }
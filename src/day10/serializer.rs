@@ -0,0 +1,5 @@
+use crate::grid::Grid;
+
+pub fn serialize_input(map: &Grid<u32>) -> String {
+    map.to_string()
+}
@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day10::Day10;
 
 static INPUT_EXAMPLE: &str = "89010123
@@ -13,10 +13,15 @@ static INPUT_EXAMPLE: &str = "89010123
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day10::default(), INPUT_EXAMPLE, 36);
+    test_part_one_common::<Day10>(INPUT_EXAMPLE, 36);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day10::default(), INPUT_EXAMPLE, 81);
+    test_part_two_common::<Day10>(INPUT_EXAMPLE, 81);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day10>(10);
 }
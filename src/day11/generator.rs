@@ -0,0 +1,10 @@
+use itertools::Itertools;
+
+use crate::generate::Rng;
+
+/// Numbers engraved on the stones, the real input has 8 of them
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(8);
+
+    (0..count).map(|_| rng.range(0..10_000_000)).join(" ") + "\n"
+}
@@ -20,7 +20,7 @@ fn get_stone_count_rec(value: usize, remaining_steps: usize, cache: &mut HashMap
     let digit_count = value.ilog10() + 1;
 
     // Get the result from recursion
-    let result = if digit_count % 2 == 0 {
+    let result = if digit_count.is_multiple_of(2) {
         // we have an even number of digit, split it and return the result for each part
         get_stone_count_rec(value / 10usize.pow(digit_count / 2), remaining_steps - 1, cache)
             + get_stone_count_rec(value % 10usize.pow(digit_count / 2), remaining_steps - 1, cache)
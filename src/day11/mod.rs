@@ -1,29 +1,77 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod reference;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day11::generator::generate;
 use crate::day11::logic::{solve_part_one, solve_part_two};
 use crate::day11::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day11::reference::{reference_part_one, reference_part_two};
+use crate::day11::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day11 {
-    parsed_data: Option<Vec<usize>>,
-}
+pub struct Day11;
 
 impl AdventSolution for Day11 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<usize>;
+    type Prepared = Vec<usize>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(11))
+    }
+
+    fn serialize(stones: &Self::Parsed) -> String {
+        serialize(stones)
+    }
+
+    fn prepare(stones: Self::Parsed) -> Self::Prepared {
+        stones
+    }
+
+    fn solve_part_one(stones: &Self::Prepared) -> Answer {
+        part_one(stones).into()
+    }
+
+    fn solve_part_two(stones: &Self::Prepared) -> Answer {
+        part_two(stones).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_one(stones: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_one(stones).into())
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_two(stones: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_two(stones).into())
     }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the engraved stones
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    Day11::parse(input.to_string())
+}
+
+/// Write the engraved stones in the input format
+pub fn serialize(stones: &[usize]) -> String {
+    serialize_input(stones)
+}
+
+/// Number of stones after 25 blinks
+pub fn part_one(stones: &[usize]) -> usize {
+    solve_part_one(stones)
+}
+
+/// Number of stones after 75 blinks
+pub fn part_two(stones: &[usize]) -> usize {
+    solve_part_two(stones)
 }
@@ -1,3 +1,18 @@
-pub fn parse_input(input: String) -> Vec<usize> {
-    input.trim().split(" ").map(|value| value.parse().unwrap()).collect()
+use crate::errors::ParseError;
+
+pub fn parse_input(input: String) -> Result<Vec<usize>, ParseError> {
+    let line = input.trim();
+
+    let mut stones = vec![];
+    let mut column = 0;
+    for value in line.split(" ") {
+        stones.push(
+            value
+                .parse()
+                .map_err(|_| ParseError::at_line(0, column, line, format!("invalid stone '{value}'")))?,
+        );
+        column += value.chars().count() + 1;
+    }
+
+    Ok(stones)
 }
@@ -0,0 +1,43 @@
+use hashbrown::HashMap;
+
+/// Apply the rules to a single stone
+fn blink(stone: usize) -> Vec<usize> {
+    let digits = stone.to_string();
+    if stone == 0 {
+        vec![1]
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        vec![left.parse().unwrap(), right.parse().unwrap()]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+/// Keep every stone in a list
+pub fn reference_part_one(stones: &[usize]) -> usize {
+    let mut stones = stones.to_vec();
+    for _ in 0..25 {
+        stones = stones.into_iter().flat_map(blink).collect();
+    }
+    stones.len()
+}
+
+/// The list of stones is too big after 75 blinks, stones with the same number are grouped instead
+pub fn reference_part_two(stones: &[usize]) -> usize {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for stone in stones {
+        *counts.entry(*stone).or_default() += 1;
+    }
+
+    for _ in 0..75 {
+        let mut next: HashMap<usize, usize> = HashMap::new();
+        for (stone, count) in counts {
+            for new_stone in blink(stone) {
+                *next.entry(new_stone).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+
+    counts.values().sum()
+}
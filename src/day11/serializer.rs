@@ -0,0 +1,5 @@
+use itertools::Itertools;
+
+pub fn serialize_input(stones: &[usize]) -> String {
+    stones.iter().join(" ") + "\n"
+}
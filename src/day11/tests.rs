@@ -1,14 +1,19 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day11::Day11;
 
 static INPUT_EXAMPLE: &str = "125 17";
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day11::default(), INPUT_EXAMPLE, 55312);
+    test_part_one_common::<Day11>(INPUT_EXAMPLE, 55312);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day11::default(), INPUT_EXAMPLE, 65601038650482);
+    test_part_two_common::<Day11>(INPUT_EXAMPLE, 65601038650482u64);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day11>(11);
 }
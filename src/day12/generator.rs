@@ -0,0 +1,25 @@
+use crate::generate::{lines, Rng};
+use crate::grid::{Coordinates, Grid};
+
+/// Garden plots where each plant grows around a few random seeds, the real input is 140 plots wide
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let side = size.unwrap_or(140).max(1);
+
+    // Each plot takes the plant of the closest seed, which creates regions of various shapes
+    let seeds: Vec<_> = (0..(side * side / 30).max(1))
+        .map(|_| {
+            let position = Coordinates::new(rng.range(0..side), rng.range(0..side));
+            (position, char::from(b'A' + rng.range(0..26) as u8))
+        })
+        .collect();
+
+    let mut garden = Grid::new(side, side, 'A');
+    for position in garden.coordinates().collect::<Vec<_>>() {
+        garden[position] = seeds
+            .iter()
+            .min_by_key(|(seed, _)| seed.manhattan_distance(&position))
+            .map_or('A', |(_, plant)| *plant);
+    }
+
+    lines(garden.rows().map(|row| row.iter().collect()))
+}
@@ -1,14 +1,10 @@
-use crate::day12::models::{MapWithBorder, Region};
+use crate::day12::models::Region;
+use crate::grid::Grid;
+use crate::logging::trace;
 
 /// Visit a neighbor
-fn visit_neighbor(
-    map: &MapWithBorder,
-    visited: &mut Vec<Vec<bool>>,
-    plant_type: char,
-    line: usize,
-    column: usize,
-) -> Region {
-    let neighbor = map.grid[line][column];
+fn visit_neighbor(map: &Grid<char>, visited: &mut Grid<bool>, plant_type: char, line: usize, column: usize) -> Region {
+    let neighbor = map[line][column];
     if neighbor != plant_type {
         // The region is terminated here, this mean we have a new border, add it to the score
         return Region::new(0, 1);
@@ -28,8 +24,8 @@ fn visit_neighbor(
 /// Returns
 /// * The tuple (area, perimeter_length)
 fn visit_region_rec(
-    map: &MapWithBorder,
-    visited: &mut Vec<Vec<bool>>,
+    map: &Grid<char>,
+    visited: &mut Grid<bool>,
     plant_type: char,
     line: usize,
     column: usize,
@@ -54,13 +50,13 @@ fn visit_region_rec(
     result
 }
 
-pub fn solve_part_one(map: &MapWithBorder) -> usize {
-    let mut visited = vec![vec![false; map.width]; map.height];
+pub fn solve_part_one(map: &Grid<char>) -> usize {
+    let mut visited = Grid::new(map.width(), map.height(), false);
     let mut total_cost = 0;
-    for line in 1..map.height - 1 {
-        for column in 1..map.width - 1 {
-            let res = visit_region_rec(map, &mut visited, map.grid[line][column], line, column);
-            // println!("{}:{line}:{column} => {res}", map.grid[line][column]);
+    for line in 1..map.height() - 1 {
+        for column in 1..map.width() - 1 {
+            let res = visit_region_rec(map, &mut visited, map[line][column], line, column);
+            trace!("{}:{line}:{column} => {res}", map[line][column]);
             total_cost += res.cost();
         }
     }
@@ -70,15 +66,15 @@ pub fn solve_part_one(map: &MapWithBorder) -> usize {
 
 /// identify a neighbor
 fn identify_neighbor(
-    map: &MapWithBorder,
-    visited: &mut Vec<Vec<bool>>,
-    regions: &mut Vec<Vec<usize>>,
+    map: &Grid<char>,
+    visited: &mut Grid<bool>,
+    regions: &mut Grid<usize>,
     plant_type: char,
     region_id: usize,
     line: usize,
     column: usize,
 ) {
-    let neighbor = map.grid[line][column];
+    let neighbor = map[line][column];
     if neighbor != plant_type {
         // We are not in our region anymore
         return;
@@ -93,9 +89,9 @@ fn identify_neighbor(
 }
 
 fn identify_regions_rec(
-    map: &MapWithBorder,
-    visited: &mut Vec<Vec<bool>>,
-    regions: &mut Vec<Vec<usize>>,
+    map: &Grid<char>,
+    visited: &mut Grid<bool>,
+    regions: &mut Grid<usize>,
     plant_type: char,
     region_id: usize,
     line: usize,
@@ -112,13 +108,13 @@ fn identify_regions_rec(
     identify_neighbor(map, visited, regions, plant_type, region_id, line, column - 1);
 }
 
-fn identify_regions(map: &MapWithBorder) -> (usize, Vec<Vec<usize>>) {
-    let mut visited = vec![vec![false; map.width]; map.height];
-    let mut regions = vec![vec![0; map.width]; map.height];
+pub fn identify_regions(map: &Grid<char>) -> (usize, Grid<usize>) {
+    let mut visited = Grid::new(map.width(), map.height(), false);
+    let mut regions = Grid::new(map.width(), map.height(), 0);
     let mut region_id: usize = 1;
 
-    for line in 1..map.height - 1 {
-        for column in 1..map.width - 1 {
+    for line in 1..map.height() - 1 {
+        for column in 1..map.width() - 1 {
             if visited[line][column] {
                 // Dont visit regions we already visited
                 continue;
@@ -128,7 +124,7 @@ fn identify_regions(map: &MapWithBorder) -> (usize, Vec<Vec<usize>>) {
                 map,
                 &mut visited,
                 &mut regions,
-                map.grid[line][column],
+                map[line][column],
                 region_id,
                 line,
                 column,
@@ -185,14 +181,14 @@ fn update_border_count(regions_border: &mut [usize], last_tuple: &(usize, usize)
     }
 }
 
-fn compute_border_count(map: &MapWithBorder, region_count: usize, regions: &[Vec<usize>]) -> Vec<usize> {
+fn compute_border_count(map: &Grid<char>, region_count: usize, regions: &Grid<usize>) -> Vec<usize> {
     // Now compute the number of border of each region
     let mut region_borders = vec![0; region_count];
 
     // horizontal traversal. Two lines at a time
-    for line in 0..map.height - 1 {
+    for line in 0..map.height() - 1 {
         let mut last_tuple = (regions[line][0], regions[line + 1][0]);
-        for column in 1..map.width - 1 {
+        for column in 1..map.width() - 1 {
             let new_tuple = (regions[line][column], regions[line + 1][column]);
 
             // Update border count
@@ -204,9 +200,9 @@ fn compute_border_count(map: &MapWithBorder, region_count: usize, regions: &[Vec
     }
 
     // Vertical traversal. Two columns at a time
-    for column in 0..map.width - 1 {
+    for column in 0..map.width() - 1 {
         let mut last_tuple = (regions[0][column], regions[0][column + 1]);
-        for line in regions.iter().take(map.height - 1).skip(1) {
+        for line in regions.rows().take(map.height() - 1).skip(1) {
             let new_tuple = (line[column], line[column + 1]);
 
             // Update border count
@@ -220,19 +216,17 @@ fn compute_border_count(map: &MapWithBorder, region_count: usize, regions: &[Vec
     region_borders
 }
 
-fn compute_regions_sizes(region_count: usize, regions: &Vec<Vec<usize>>) -> Vec<usize> {
+fn compute_regions_sizes(region_count: usize, regions: &Grid<usize>) -> Vec<usize> {
     let mut regions_sizes = vec![0; region_count];
 
-    for line in regions {
-        for tile in line {
-            regions_sizes[*tile] += 1;
-        }
+    for (_, tile) in regions.iter() {
+        regions_sizes[*tile] += 1;
     }
 
     regions_sizes
 }
 
-pub fn solve_part_two(map: &MapWithBorder) -> usize {
+pub fn solve_part_two(map: &Grid<char>) -> usize {
     // First, assign a unique id to every region
     let (region_count, regions) = identify_regions(map);
 
@@ -1,30 +1,75 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
+mod visualization;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day12::generator::generate;
 use crate::day12::logic::{solve_part_one, solve_part_two};
-use crate::day12::models::MapWithBorder;
 use crate::day12::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day12::serializer::serialize_input;
+use crate::day12::visualization::picture;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::Color;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day12 {
-    parsed_data: Option<MapWithBorder>,
-}
+pub struct Day12;
 
 impl AdventSolution for Day12 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Grid<char>;
+    type Prepared = Grid<char>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(12))
+    }
+
+    fn serialize(map: &Self::Parsed) -> String {
+        serialize(map)
+    }
+
+    fn prepare(map: Self::Parsed) -> Self::Prepared {
+        map
+    }
+
+    fn solve_part_one(map: &Self::Prepared) -> Answer {
+        part_one(map).into()
+    }
+
+    fn solve_part_two(map: &Self::Prepared) -> Answer {
+        part_two(map).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn export(map: &Self::Parsed, _step: Option<usize>) -> Option<Grid<Color>> {
+        Some(picture(map))
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the garden map, with a border around it
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Day12::parse(input.to_string())
+}
+
+/// Write the garden map in the input format, without its border
+pub fn serialize(map: &Grid<char>) -> String {
+    serialize_input(map)
+}
+
+/// Price of the fences computed with the perimeter of the regions
+pub fn part_one(map: &Grid<char>) -> usize {
+    solve_part_one(map)
+}
+
+/// Price of the fences computed with the number of sides of the regions
+pub fn part_two(map: &Grid<char>) -> usize {
+    solve_part_two(map)
+}
@@ -1,5 +1,8 @@
-use crate::day12::models::MapWithBorder;
+use crate::errors::ParseError;
+use crate::grid::Grid;
+use crate::parsing::char_grid;
 
-pub fn parse_input(input: String) -> MapWithBorder {
-    MapWithBorder::new(input.lines().map(|line| line.chars().collect()).collect())
+pub fn parse_input(input: String) -> Result<Grid<char>, ParseError> {
+    // Add a border to simplify further computation
+    Ok(char_grid(&input)?.padded(1, '.'))
 }
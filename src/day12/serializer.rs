@@ -0,0 +1,6 @@
+use crate::grid::Grid;
+
+pub fn serialize_input(map: &Grid<char>) -> String {
+    // Remove the border added by the parser
+    map.unpadded(1).to_string()
+}
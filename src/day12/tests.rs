@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day12::Day12;
 
 static INPUT_EXAMPLE: &str = "RRRRIICCFF
@@ -15,10 +15,15 @@ MMMISSJEEE
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day12::default(), INPUT_EXAMPLE, 1930);
+    test_part_one_common::<Day12>(INPUT_EXAMPLE, 1930);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day12::default(), INPUT_EXAMPLE, 1206);
+    test_part_two_common::<Day12>(INPUT_EXAMPLE, 1206);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day12>(12);
 }
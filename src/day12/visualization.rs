@@ -0,0 +1,17 @@
+use crate::day12::logic::identify_regions;
+use crate::grid::Grid;
+use crate::image::Color;
+
+/// Picture of the garden where each region has its own colour
+pub fn picture(map: &Grid<char>) -> Grid<Color> {
+    let (_, regions) = identify_regions(map);
+
+    // Region 0 is the border added by the parser
+    regions.map(|region| {
+        if *region == 0 {
+            Color::BLACK
+        } else {
+            Color::indexed(*region - 1)
+        }
+    })
+}
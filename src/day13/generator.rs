@@ -0,0 +1,36 @@
+use crate::generate::Rng;
+
+/// Claw machines, the real input has 320 of them.
+///
+/// The prize of half of the machines can be won by pressing the buttons at most 100 times.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(320);
+
+    let machines: Vec<_> = (0..count)
+        .map(|_| {
+            // Buttons must not move the claw in the same direction, otherwise the linear system
+            // has infinitely many solutions
+            let (a, b) = loop {
+                let a = (rng.range(10..100), rng.range(10..100));
+                let b = (rng.range(10..100), rng.range(10..100));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+
+            let prize = if rng.chance(0.5) {
+                let (presses_a, presses_b) = (rng.range(1..101), rng.range(0..101));
+                (presses_a * a.0 + presses_b * b.0, presses_a * a.1 + presses_b * b.1)
+            } else {
+                (rng.range(1000..20000), rng.range(1000..20000))
+            };
+
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+
+    machines.join("\n")
+}
@@ -1,6 +1,7 @@
 use std::cmp::{max, min};
 
 use crate::day13::models::{DiophantineSolution, Machine};
+use crate::logging::trace;
 
 pub fn solve_part_one(machines: &[Machine]) -> i64 {
     let mut total_cost = 0;
@@ -79,7 +80,7 @@ fn solve_diophantine(a: i64, b: i64, c: i64) -> Option<DiophantineSolution> {
 
     // This equation admit a solution if and only if c is a multiple of gcd(a, b)
     if c % gcd != 0 {
-        // println!("no solution because of the gcd");
+        trace!("no solution because of the gcd");
         return None;
     }
 
@@ -110,7 +111,7 @@ fn solve_diophantine(a: i64, b: i64, c: i64) -> Option<DiophantineSolution> {
     }
 
     // If we can, offset x0 and yo to only have positive values of n.
-    // println!("before:{min_n}:{max_n}");
+    trace!("before:{min_n}:{max_n}");
     if min_n == i64::MIN {
         unimplemented!("Houston we have an unhandled case");
     }
@@ -208,8 +209,10 @@ pub fn solve_part_two(machines: &[Machine]) -> i64 {
             let res_a = res0.x0 - n * res0.a0;
             let res_b = res0.y0 + n * res0.b0;
 
-            // Add it to the cost
-            total_cost += res_a * 3 + res_b;
+            // Buttons cannot be pressed a negative number of times
+            if res_a >= 0 && res_b >= 0 {
+                total_cost += res_a * 3 + res_b;
+            }
         }
     }
 
@@ -1,30 +1,78 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod reference;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day13::generator::generate;
 use crate::day13::logic::{solve_part_one, solve_part_two};
-use crate::day13::models::Machine;
+pub use crate::day13::models::Machine;
 use crate::day13::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day13::reference::{reference_part_one, reference_part_two};
+use crate::day13::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day13 {
-    parsed_data: Option<Vec<Machine>>,
-}
+pub struct Day13;
 
 impl AdventSolution for Day13 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<Machine>;
+    type Prepared = Vec<Machine>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(13))
+    }
+
+    fn serialize(machines: &Self::Parsed) -> String {
+        serialize(machines)
+    }
+
+    fn prepare(machines: Self::Parsed) -> Self::Prepared {
+        machines
+    }
+
+    fn solve_part_one(machines: &Self::Prepared) -> Answer {
+        part_one(machines).into()
+    }
+
+    fn solve_part_two(machines: &Self::Prepared) -> Answer {
+        part_two(machines).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_one(machines: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_one(machines).into())
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_two(machines: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_two(machines).into())
     }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the claw machines
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    Day13::parse(input.to_string())
+}
+
+/// Write the claw machines in the input format
+pub fn serialize(machines: &[Machine]) -> String {
+    serialize_input(machines)
+}
+
+/// Fewest tokens needed to win every prize that can be won
+pub fn part_one(machines: &[Machine]) -> i64 {
+    solve_part_one(machines)
+}
+
+/// Fewest tokens needed once the prizes are moved by 10 000 000 000 000
+pub fn part_two(machines: &[Machine]) -> i64 {
+    solve_part_two(machines)
 }
@@ -9,11 +9,10 @@ pub struct Machine {
 
 impl Display for Machine {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "(A:+{:<3},+{:<3}; B:+{:<3},+{:<3}; target:{:6},{:6})",
-            self.a.0, self.a.1, self.b.0, self.b.1, self.target.0, self.target.1
-        )
+        // Same format as the puzzle input
+        writeln!(f, "Button A: X+{}, Y+{}", self.a.0, self.a.1)?;
+        writeln!(f, "Button B: X+{}, Y+{}", self.b.0, self.b.1)?;
+        write!(f, "Prize: X={}, Y={}", self.target.0, self.target.1)
     }
 }
 
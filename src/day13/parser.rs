@@ -1,37 +1,33 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{i64 as i64_parser, line_ending};
+use nom::character::complete::line_ending;
 use nom::combinator::map;
-use nom::multi::{many0, many1};
-use nom::sequence::tuple;
+use nom::multi::many0;
+use nom::sequence::{preceded, separated_pair, terminated, tuple};
 use nom::{IResult, Parser};
 
 use crate::day13::models::Machine;
+use crate::errors::ParseError;
+use crate::parsing::{finish, key_value, many1_complete, unsigned};
 
 fn parse_button(input: &str) -> IResult<&str, (i64, i64)> {
-    map(
-        tuple((
-            alt((tag("Button A: X+"), tag("Button B: X+"))),
-            i64_parser,
-            tag(", Y+"),
-            i64_parser,
-            many0(line_ending),
-        )),
-        |(_, x, _, y, _)| (x, y),
+    terminated(
+        preceded(
+            alt((tag("Button A: "), tag("Button B: "))),
+            separated_pair(key_value("X", "+", unsigned), tag(", "), key_value("Y", "+", unsigned)),
+        ),
+        many0(line_ending),
     )
     .parse(input)
 }
 
 fn parse_prize(input: &str) -> IResult<&str, (i64, i64)> {
-    map(
-        tuple((
-            tag("Prize: X="),
-            i64_parser,
-            tag(", Y="),
-            i64_parser,
-            many0(line_ending),
-        )),
-        |(_, x, _, y, _)| (x, y),
+    terminated(
+        preceded(
+            tag("Prize: "),
+            separated_pair(key_value("X", "=", unsigned), tag(", "), key_value("Y", "=", unsigned)),
+        ),
+        many0(line_ending),
     )
     .parse(input)
 }
@@ -43,11 +39,6 @@ fn parse_machine(input: &str) -> IResult<&str, Machine> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Machine> {
-    let (res, games) = many1(parse_machine).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Vec<Machine>, ParseError> {
+    finish(&input, many1_complete(parse_machine).parse(&input))
 }
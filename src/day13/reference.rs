@@ -0,0 +1,41 @@
+use crate::day13::models::Machine;
+
+/// Try every number of presses of both buttons
+pub fn reference_part_one(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            (0..=100)
+                .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                .filter(|(a, b)| {
+                    a * machine.a.0 + b * machine.b.0 == machine.target.0
+                        && a * machine.a.1 + b * machine.b.1 == machine.target.1
+                })
+                .map(|(a, b)| 3 * a + b)
+                .min()
+        })
+        .sum()
+}
+
+/// Solve the linear system of each machine with the Cramer's rule
+pub fn reference_part_two(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            let (x, y) = (
+                machine.target.0 + 10_000_000_000_000,
+                machine.target.1 + 10_000_000_000_000,
+            );
+            let determinant = machine.a.0 * machine.b.1 - machine.a.1 * machine.b.0;
+            if determinant == 0 {
+                return None;
+            }
+
+            let a = x * machine.b.1 - y * machine.b.0;
+            let b = machine.a.0 * y - machine.a.1 * x;
+            let is_integral = a % determinant == 0 && b % determinant == 0;
+            let (a, b) = (a / determinant, b / determinant);
+            (is_integral && a >= 0 && b >= 0).then_some(3 * a + b)
+        })
+        .sum()
+}
@@ -0,0 +1,8 @@
+use itertools::Itertools;
+
+use crate::day13::models::Machine;
+
+pub fn serialize_input(machines: &[Machine]) -> String {
+    // Machines are displayed in the input format
+    machines.iter().join("\n\n") + "\n"
+}
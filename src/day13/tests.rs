@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day13::Day13;
 
 static INPUT_EXAMPLE: &str = "Button A: X+94, Y+34
@@ -20,10 +20,22 @@ Prize: X=18641, Y=10279
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day13::default(), INPUT_EXAMPLE, 480);
+    test_part_one_common::<Day13>(INPUT_EXAMPLE, 480);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day13::default(), INPUT_EXAMPLE, 875318608908);
+    test_part_two_common::<Day13>(INPUT_EXAMPLE, 875318608908u64);
+}
+
+#[test]
+fn test_regression_negative_presses() {
+    // The only way to reach the far away prize is to press A 10000000000012 times and B -1 times
+    let input = "Button A: X+1, Y+2\nButton B: X+2, Y+1\nPrize: X=10, Y=10000000000023\n";
+    test_part_two_common::<Day13>(input, 0);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day13>(13);
 }
@@ -0,0 +1,37 @@
+use crate::generate::{lines, Rng};
+
+/// Size of the real bathroom, smaller ones are only used by the example
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+/// Height of the Christmas tree drawn by the robots
+const TREE_HEIGHT: i64 = 12;
+
+/// Robots moving in the bathroom, the real input has 500 of them.
+///
+/// Some of the robots draw a Christmas tree after a random number of seconds, their initial
+/// positions are found by moving them back in time from the tree.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(500);
+    let seconds = rng.signed(1..=WIDTH * HEIGHT - 1);
+
+    // Triangle whose middle column is the line of robots the solver looks for
+    let top = (
+        rng.signed(TREE_HEIGHT..=WIDTH - TREE_HEIGHT),
+        rng.signed(0..=HEIGHT - TREE_HEIGHT),
+    );
+    let tree = (0..TREE_HEIGHT).flat_map(|y| (-y..=y).map(move |x| (top.0 + x, top.1 + y)));
+
+    let mut positions: Vec<_> = tree.collect();
+    while positions.len() < count {
+        positions.push((rng.signed(0..=WIDTH - 1), rng.signed(0..=HEIGHT - 1)));
+    }
+    rng.shuffle(&mut positions);
+
+    lines(positions.into_iter().map(|(x, y)| {
+        let (vx, vy) = (rng.signed(-99..=99), rng.signed(-99..=99));
+        let start_x = (x - vx * seconds).rem_euclid(WIDTH);
+        let start_y = (y - vy * seconds).rem_euclid(HEIGHT);
+        format!("p={start_x},{start_y} v={vx},{vy}")
+    }))
+}
@@ -1,6 +1,7 @@
 use hashbrown::{HashMap, HashSet};
 
 use crate::day14::models::{Bathroom, Robot};
+use crate::logging::debug;
 
 fn simulate_robot(robot: &Robot, width: i64, height: i64, steps: usize) -> (i64, i64) {
     let mut cur_x = robot.x;
@@ -44,7 +45,7 @@ pub fn solve_part_one(bathroom: &Bathroom) -> usize {
     q1 * q2 * q3 * q4
 }
 
-fn step(bathroom: &mut Bathroom) {
+pub fn step(bathroom: &mut Bathroom) {
     for robot in &mut bathroom.robots {
         robot.x = (robot.x + robot.vx + bathroom.width) % bathroom.width;
         robot.y = (robot.y + robot.vy + bathroom.height) % bathroom.height;
@@ -67,10 +68,10 @@ fn check_if_might_work(bathroom: &Bathroom) -> bool {
     false
 }
 
-pub fn solve_part_two(bathroom: &Bathroom) -> usize {
-    // Part two does not work for the example, return 0 for it
+pub fn solve_part_two(bathroom: &Bathroom) -> Option<usize> {
+    // Part two does not work for the example
     if bathroom.width == 11 {
-        return 0;
+        return None;
     }
 
     let mut bathroom = bathroom.clone();
@@ -81,10 +82,10 @@ pub fn solve_part_two(bathroom: &Bathroom) -> usize {
         step(&mut bathroom);
 
         if check_if_might_work(&bathroom) {
-            // println!("{}", &bathroom);
+            debug!("possible tree after {current_step} steps:\n{}", &bathroom);
             break;
         }
     }
 
-    current_step
+    Some(current_step)
 }
@@ -1,30 +1,80 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
+mod visualization;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day14::generator::generate;
 use crate::day14::logic::{solve_part_one, solve_part_two};
-use crate::day14::models::Bathroom;
+pub use crate::day14::models::Bathroom;
 use crate::day14::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day14::serializer::serialize_input;
+use crate::day14::visualization::{frames, picture};
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::Color;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day14 {
-    parsed_data: Option<Bathroom>,
-}
+pub struct Day14;
 
 impl AdventSolution for Day14 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Bathroom;
+    type Prepared = Bathroom;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(14))
+    }
+
+    fn serialize(bathroom: &Self::Parsed) -> String {
+        serialize(bathroom)
+    }
+
+    fn prepare(bathroom: Self::Parsed) -> Self::Prepared {
+        bathroom
+    }
+
+    fn solve_part_one(bathroom: &Self::Prepared) -> Answer {
+        part_one(bathroom).into()
+    }
+
+    fn solve_part_two(bathroom: &Self::Prepared) -> Answer {
+        part_two(bathroom).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn visualize(bathroom: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(frames(bathroom)))
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn export(bathroom: &Self::Parsed, step: Option<usize>) -> Option<Grid<Color>> {
+        Some(picture(bathroom, step))
     }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the robots
+pub fn parse(input: &str) -> Result<Bathroom, ParseError> {
+    Day14::parse(input.to_string())
+}
+
+/// Write the robots in the input format
+pub fn serialize(bathroom: &Bathroom) -> String {
+    serialize_input(bathroom)
+}
+
+/// Safety factor after 100 seconds
+pub fn part_one(bathroom: &Bathroom) -> usize {
+    solve_part_one(bathroom)
+}
+
+/// Seconds until the robots display a Christmas tree, `None` for the example
+pub fn part_two(bathroom: &Bathroom) -> Option<usize> {
+    solve_part_two(bathroom)
 }
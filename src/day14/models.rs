@@ -8,6 +8,12 @@ pub struct Robot {
     pub vy: i64,
 }
 
+impl Display for Robot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "p={},{} v={},{}", self.x, self.y, self.vx, self.vy)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Bathroom {
     pub robots: Vec<Robot>,
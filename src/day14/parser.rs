@@ -1,49 +1,47 @@
 use nom::bytes::complete::tag;
-use nom::character::complete::{i64 as i64_parser, line_ending};
-use nom::combinator::{map, opt};
-use nom::multi::many1;
-use nom::sequence::tuple;
+use nom::combinator::map;
+use nom::sequence::separated_pair;
 use nom::{IResult, Parser};
 
 use crate::day14::models::{Bathroom, Robot};
+use crate::errors::ParseError;
+use crate::parsing::{finish, key_value, line, many1_complete, number_pair};
 
 fn parse_robot(input: &str) -> IResult<&str, Robot> {
     map(
-        tuple((
-            tag("p="),
-            i64_parser,
-            tag(","),
-            i64_parser,
-            tag(" v="),
-            i64_parser,
-            tag(","),
-            i64_parser,
-            opt(line_ending),
+        line(separated_pair(
+            key_value("p", "=", number_pair(",")),
+            tag(" "),
+            key_value("v", "=", number_pair(",")),
         )),
-        |(_, x, _, y, _, vx, _, vy, _)| Robot { x, y, vx, vy },
+        |((x, y), (vx, vy))| Robot { x, y, vx, vy },
     )
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Bathroom {
-    let (res, robots) = many1(parse_robot).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
+pub fn parse_input(input: String) -> Result<Bathroom, ParseError> {
+    let robots = finish(&input, many1_complete(parse_robot).parse(&input))?;
 
     // Compute bathroom size according to robot initial positions.
     // This allows us to detect test case from real case
-    if robots.iter().all(|robot| robot.x < 11 && robot.y < 7) {
-        Bathroom {
-            robots,
-            width: 11,
-            height: 7,
-        }
+    let (width, height) = if robots.iter().all(|robot| robot.x < 11 && robot.y < 7) {
+        (11, 7)
     } else {
-        Bathroom {
-            robots,
-            width: 101,
-            height: 103,
+        (101, 103)
+    };
+
+    // Robots outside the bathroom cannot be simulated
+    for (i, robot) in robots.iter().enumerate() {
+        if !(0..width).contains(&robot.x) || !(0..height).contains(&robot.y) {
+            let line = input.lines().nth(i).unwrap_or_default();
+            return Err(ParseError::at_line(
+                i,
+                0,
+                line,
+                format!("robot is outside of the {width}x{height} bathroom"),
+            ));
         }
     }
+
+    Ok(Bathroom { robots, width, height })
 }
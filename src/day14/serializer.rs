@@ -0,0 +1,7 @@
+use crate::day14::models::Bathroom;
+use crate::generate::lines;
+
+pub fn serialize_input(bathroom: &Bathroom) -> String {
+    // The size of the bathroom is not part of the input, it is deduced from the robots
+    lines(bathroom.robots.iter().map(|robot| robot.to_string()))
+}
@@ -1,14 +1,20 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day14::Day14;
+use crate::models::Answer;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day14");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day14::default(), INPUT_EXAMPLE, 12);
+    test_part_one_common::<Day14>(INPUT_EXAMPLE, 12);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day14::default(), INPUT_EXAMPLE, 0);
+    test_part_two_common::<Day14>(INPUT_EXAMPLE, Answer::NotApplicable);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day14>(14);
 }
@@ -0,0 +1,46 @@
+use colored::Colorize;
+
+use crate::day14::logic::{solve_part_two, step as step_robots};
+use crate::day14::models::Bathroom;
+use crate::grid::Grid;
+use crate::image::Color;
+use crate::visualize::render_grid;
+
+/// Show the robots moving, one frame per second until they are back to their initial positions
+pub fn frames(bathroom: &Bathroom) -> impl Iterator<Item = String> {
+    let mut bathroom = bathroom.clone();
+    let period = bathroom.width * bathroom.height;
+
+    (0..=period).map(move |second| {
+        if second > 0 {
+            step_robots(&mut bathroom);
+        }
+
+        let grid = Grid::from_rows(bathroom.create_grid());
+        let frame = render_grid(
+            &grid,
+            |_, robots| {
+                if *robots == 0 {
+                    ".".dimmed()
+                } else {
+                    "#".green().bold()
+                }
+            },
+        );
+
+        format!("after {second} seconds\n{frame}")
+    })
+}
+
+/// Picture of the robots after `step` seconds, or when they draw the Christmas tree
+pub fn picture(bathroom: &Bathroom, step: Option<usize>) -> Grid<Color> {
+    // The example never draws a tree, its initial state is used instead
+    let seconds = step.or_else(|| solve_part_two(bathroom)).unwrap_or(0);
+
+    let mut bathroom = bathroom.clone();
+    for _ in 0..seconds {
+        step_robots(&mut bathroom);
+    }
+
+    Grid::from_rows(bathroom.create_grid()).map(|robots| if *robots == 0 { Color::BLACK } else { Color::GREEN })
+}
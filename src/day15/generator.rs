@@ -0,0 +1,26 @@
+use crate::generate::{lines, word, Rng};
+use crate::grid::{Coordinates, Grid};
+
+/// Warehouse full of boxes surrounded by walls followed by the moves of the robot, the real input
+/// is 50 tiles wide with 20000 moves
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let side = size.unwrap_or(50).max(3);
+
+    let mut warehouse = Grid::new(side, side, '#');
+    for y in 1..side - 1 {
+        for x in 1..side - 1 {
+            warehouse[y][x] = match rng.range(0..20) {
+                0 => '#',
+                1..=5 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    warehouse[Coordinates::new(rng.range(1..side - 1), rng.range(1..side - 1))] = '@';
+
+    // Moves are given on lines of 1000 characters
+    let moves = side * 400;
+    let instructions = (0..moves.div_ceil(1000)).map(|i| word(rng, b"^>v<", (moves - i * 1000).min(1000)));
+
+    lines(warehouse.rows().map(|row| row.iter().collect())) + "\n" + &lines(instructions)
+}
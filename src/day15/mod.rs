@@ -1,31 +1,74 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
+mod visualization;
 
 #[cfg(test)]
 mod tests;
 
-use models::warehouse::Warehouse;
-
+use crate::day15::generator::generate;
 use crate::day15::logic::{solve_part_one, solve_part_two};
+pub use crate::day15::models::warehouse::Warehouse;
 use crate::day15::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day15::serializer::serialize_input;
+use crate::day15::visualization::frames;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day15 {
-    parsed_data: Option<Warehouse>,
-}
+pub struct Day15;
 
 impl AdventSolution for Day15 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Warehouse;
+    type Prepared = Warehouse;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(15))
+    }
+
+    fn serialize(warehouse: &Self::Parsed) -> String {
+        serialize(warehouse)
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn prepare(warehouse: Self::Parsed) -> Self::Prepared {
+        warehouse
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_one(warehouse: &Self::Prepared) -> Answer {
+        part_one(warehouse).into()
     }
+
+    fn solve_part_two(warehouse: &Self::Prepared) -> Answer {
+        part_two(warehouse).into()
+    }
+
+    fn visualize(warehouse: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(frames(warehouse)))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the warehouse and the moves of the robot
+pub fn parse(input: &str) -> Result<Warehouse, ParseError> {
+    Day15::parse(input.to_string())
+}
+
+/// Write the warehouse and the moves of the robot in the input format
+pub fn serialize(warehouse: &Warehouse) -> String {
+    serialize_input(warehouse)
+}
+
+/// Sum of the GPS coordinates of the boxes
+pub fn part_one(warehouse: &Warehouse) -> usize {
+    solve_part_one(warehouse)
+}
+
+/// Sum of the GPS coordinates of the boxes in the wide warehouse
+pub fn part_two(warehouse: &Warehouse) -> usize {
+    solve_part_two(warehouse)
 }
@@ -1,16 +1,11 @@
 use std::collections::VecDeque;
 use std::fmt::{Display, Formatter};
-use std::ops::{Index, IndexMut};
 
 use hashbrown::HashSet;
-use once_cell::sync::Lazy;
 
-use crate::day15::models::coordinates::Coordinates;
 use crate::day15::models::instruction::Instruction;
 use crate::day15::models::warehouse::{MapTile, Warehouse};
-
-static LEFT: Lazy<Coordinates> = Lazy::new(|| Coordinates::new(-1, 0));
-static RIGHT: Lazy<Coordinates> = Lazy::new(|| Coordinates::new(1, 0));
+use crate::grid::{Coordinates, Direction, Grid};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum BigMapTile {
@@ -31,26 +26,9 @@ impl Display for BigMapTile {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct BigGrid(pub Vec<Vec<BigMapTile>>);
-
-impl Index<&Coordinates> for BigGrid {
-    type Output = BigMapTile;
-
-    fn index(&self, index: &Coordinates) -> &Self::Output {
-        &self.0[index.y as usize][index.x as usize]
-    }
-}
-
-impl IndexMut<&Coordinates> for BigGrid {
-    fn index_mut(&mut self, index: &Coordinates) -> &mut Self::Output {
-        &mut self.0[index.y as usize][index.x as usize]
-    }
-}
-
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BigWarehouse {
-    pub grid: BigGrid,
+    pub grid: Grid<BigMapTile>,
     pub robot_positions: Coordinates,
     pub instructions: Vec<Instruction>,
 }
@@ -58,11 +36,10 @@ pub struct BigWarehouse {
 impl From<&Warehouse> for BigWarehouse {
     fn from(value: &Warehouse) -> Self {
         // Transform the warehouse map to build a big warehouse map
-        let grid = BigGrid(
+        let grid = Grid::from_rows(
             value
                 .grid
-                .0
-                .iter()
+                .rows()
                 .map(|line| {
                     line.iter()
                         .flat_map(|tile| match tile {
@@ -88,15 +65,15 @@ impl From<&Warehouse> for BigWarehouse {
 
 impl Display for BigWarehouse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (y, line) in self.grid.0.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                if self.robot_positions == (x, y) {
-                    write!(f, "@")?;
-                } else {
-                    write!(f, "{tile}")?;
-                }
+        for (position, tile) in self.grid.iter() {
+            if position == self.robot_positions {
+                write!(f, "@")?;
+            } else {
+                write!(f, "{tile}")?;
+            }
+            if position.x + 1 == self.grid.width() {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
         Ok(())
     }
@@ -104,10 +81,10 @@ impl Display for BigWarehouse {
 
 impl BigWarehouse {
     pub fn move_robot(&mut self, instruction: &Instruction) {
-        // Get the movement vector
-        let movement_vector = instruction.get_movement_vec();
+        // Get the movement direction
+        let direction = instruction.get_direction();
 
-        let mut to_check = VecDeque::from([self.robot_positions.clone()]);
+        let mut to_check = VecDeque::from([self.robot_positions]);
         let mut checked = HashSet::new();
 
         // The robot can move if an empty tile is located in the moving direction
@@ -116,18 +93,18 @@ impl BigWarehouse {
         // we now that our loop will always end
         while let Some(mut current) = to_check.pop_front() {
             // Move it
-            current += &movement_vector;
+            current = current + direction;
 
             // If the position is a wall, nothing can move, return
-            if self.grid[&current] == BigMapTile::Wall {
+            if self.grid[current] == BigMapTile::Wall {
                 return;
             }
 
             // Check if the current position contains a box
-            let big_box = if self.grid[&current] == BigMapTile::LeftBox {
-                Some(current.clone())
-            } else if self.grid[&current] == BigMapTile::RightBox {
-                Some(current.clone() + &LEFT)
+            let big_box = if self.grid[current] == BigMapTile::LeftBox {
+                Some(current)
+            } else if self.grid[current] == BigMapTile::RightBox {
+                Some(current + Direction::Left)
             } else {
                 None
             };
@@ -135,8 +112,8 @@ impl BigWarehouse {
             // If so, check if we already handled it, if not add its coordinates to the checklist
             if let Some(big_box) = big_box {
                 if !checked.contains(&big_box) {
-                    to_check.push_back(big_box.clone());
-                    to_check.push_back(big_box.clone() + &RIGHT);
+                    to_check.push_back(big_box);
+                    to_check.push_back(big_box + Direction::Right);
                     checked.insert(big_box);
                 }
             }
@@ -145,29 +122,23 @@ impl BigWarehouse {
         // Here we should have a list of box to move
         // 1. Remove old boxes
         for big_box in &checked {
-            self.grid[big_box] = BigMapTile::Empty;
-            self.grid[&(big_box.clone() + &RIGHT)] = BigMapTile::Empty;
+            self.grid[*big_box] = BigMapTile::Empty;
+            self.grid[*big_box + Direction::Right] = BigMapTile::Empty;
         }
         // 2. Add new ones
-        for mut big_box in checked {
-            big_box += &movement_vector;
-            self.grid[&big_box] = BigMapTile::LeftBox;
-            big_box += &RIGHT;
-            self.grid[&big_box] = BigMapTile::RightBox;
+        for big_box in checked {
+            let big_box = big_box + direction;
+            self.grid[big_box] = BigMapTile::LeftBox;
+            self.grid[big_box + Direction::Right] = BigMapTile::RightBox;
         }
         // 3. Move the robot
-        self.robot_positions += &movement_vector;
+        self.robot_positions = self.robot_positions + direction;
     }
 
     pub fn boxes_score(&self) -> usize {
-        let mut score = 0;
-        for (y, line) in self.grid.0.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                if tile == &BigMapTile::LeftBox {
-                    score += 100 * y + x;
-                }
-            }
-        }
-        score
+        self.grid
+            .find_all(&BigMapTile::LeftBox)
+            .map(|position| 100 * position.y + position.x)
+            .sum()
     }
 }
@@ -1,4 +1,4 @@
-use crate::day15::models::coordinates::Coordinates;
+use crate::grid::Direction;
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
 pub enum Instruction {
@@ -8,25 +8,38 @@ pub enum Instruction {
     Left,
 }
 
-impl From<char> for Instruction {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Instruction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Self::Up),
+            '>' => Ok(Self::Right),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            _ => Err(format!("unknown instruction '{value}'")),
+        }
+    }
+}
+
+impl From<Instruction> for char {
+    fn from(value: Instruction) -> Self {
         match value {
-            '^' => Self::Up,
-            '>' => Self::Right,
-            'v' => Self::Down,
-            '<' => Self::Left,
-            _ => unreachable!(),
+            Instruction::Up => '^',
+            Instruction::Right => '>',
+            Instruction::Down => 'v',
+            Instruction::Left => '<',
         }
     }
 }
 
 impl Instruction {
-    pub fn get_movement_vec(&self) -> Coordinates {
+    pub fn get_direction(&self) -> Direction {
         match self {
-            Instruction::Up => Coordinates::new(0, -1),
-            Instruction::Right => Coordinates::new(1, 0),
-            Instruction::Down => Coordinates::new(0, 1),
-            Instruction::Left => Coordinates::new(-1, 0),
+            Instruction::Up => Direction::Up,
+            Instruction::Right => Direction::Right,
+            Instruction::Down => Direction::Down,
+            Instruction::Left => Direction::Left,
         }
     }
 }
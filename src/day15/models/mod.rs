@@ -1,4 +1,3 @@
 pub mod big_warehouse;
-pub mod coordinates;
 pub mod instruction;
 pub mod warehouse;
@@ -1,8 +1,7 @@
 use std::fmt::{Display, Formatter};
-use std::ops::{Index, IndexMut};
 
-use crate::day15::models::coordinates::Coordinates;
 use crate::day15::models::instruction::Instruction;
+use crate::grid::{Coordinates, Grid};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MapTile {
@@ -21,41 +20,24 @@ impl Display for MapTile {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Grid(pub Vec<Vec<MapTile>>);
-
-impl Index<&Coordinates> for Grid {
-    type Output = MapTile;
-
-    fn index(&self, index: &Coordinates) -> &Self::Output {
-        &self.0[index.y as usize][index.x as usize]
-    }
-}
-
-impl IndexMut<&Coordinates> for Grid {
-    fn index_mut(&mut self, index: &Coordinates) -> &mut Self::Output {
-        &mut self.0[index.y as usize][index.x as usize]
-    }
-}
-
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Warehouse {
-    pub grid: Grid,
+    pub grid: Grid<MapTile>,
     pub robot_positions: Coordinates,
     pub instructions: Vec<Instruction>,
 }
 
 impl Display for Warehouse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (y, line) in self.grid.0.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                if self.robot_positions == (x, y) {
-                    write!(f, "@")?;
-                } else {
-                    write!(f, "{tile}")?;
-                }
+        for (position, tile) in self.grid.iter() {
+            if position == self.robot_positions {
+                write!(f, "@")?;
+            } else {
+                write!(f, "{tile}")?;
+            }
+            if position.x + 1 == self.grid.width() {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
         Ok(())
     }
@@ -64,9 +46,9 @@ impl Display for Warehouse {
 impl Warehouse {
     /// Check if the robot can move in the desired direction
     pub fn move_robot(&mut self, instruction: &Instruction) {
-        let mut check = self.robot_positions.clone();
+        let mut check = self.robot_positions;
         let mut boxed = false; // true if we found a box on the way
-        let movement_vector = instruction.get_movement_vec();
+        let direction = instruction.get_direction();
 
         // The robot can move if an empty tile is located in the moving direction
         // and if no wall is present before.
@@ -74,10 +56,10 @@ impl Warehouse {
         // we now that our loop will always end
         loop {
             // move the robot
-            check += &movement_vector;
+            check = check + direction;
 
             // Check the new position
-            match &self.grid[&check] {
+            match &self.grid[check] {
                 MapTile::Wall => {
                     // Nothing to do return
                     return;
@@ -85,15 +67,15 @@ impl Warehouse {
                 MapTile::Box => boxed = true,
                 MapTile::Empty => {
                     // Move the robot
-                    self.robot_positions += &movement_vector;
+                    self.robot_positions = self.robot_positions + direction;
 
                     // Move the line of boxes if needed
                     if boxed {
                         // Move the line of boxes
                         // 1. from the start
-                        self.grid[&self.robot_positions] = MapTile::Empty;
+                        self.grid[self.robot_positions] = MapTile::Empty;
                         // 2. to the end
-                        self.grid[&check] = MapTile::Box;
+                        self.grid[check] = MapTile::Box;
                     }
 
                     return;
@@ -103,14 +85,9 @@ impl Warehouse {
     }
 
     pub fn boxes_score(&self) -> usize {
-        let mut score = 0;
-        for (y, line) in self.grid.0.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                if tile == &MapTile::Box {
-                    score += 100 * y + x;
-                }
-            }
-        }
-        score
+        self.grid
+            .find_all(&MapTile::Box)
+            .map(|position| 100 * position.y + position.x)
+            .sum()
     }
 }
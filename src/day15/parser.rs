@@ -1,42 +1,52 @@
-use crate::day15::models::coordinates::Coordinates;
 use crate::day15::models::instruction::Instruction;
-use crate::day15::models::warehouse::{Grid, MapTile, Warehouse};
+use crate::day15::models::warehouse::{MapTile, Warehouse};
+use crate::errors::ParseError;
+use crate::grid::Grid;
+use crate::parsing::sections;
 
-pub fn parse_input(input: String) -> Warehouse {
-    let mut grid: Vec<Vec<MapTile>> = vec![];
-    let mut robot_positions = Coordinates::default();
-    let mut instructions: Vec<Instruction> = vec![];
-    let mut grid_completed = false;
+pub fn parse_input(input: String) -> Result<Warehouse, ParseError> {
+    // The map is followed by the instructions
+    let sections = sections(&input);
+    let Some((_, map)) = sections.first() else {
+        return Err(ParseError::at_line(0, 0, "", "expected a map"));
+    };
+
+    let mut robot_positions = None;
+    let grid = Grid::parse(map, |position, tile| match tile {
+        '#' => Ok(MapTile::Wall),
+        '.' => Ok(MapTile::Empty),
+        'O' => Ok(MapTile::Box),
+        '@' => {
+            robot_positions = Some(position);
+            Ok(MapTile::Empty)
+        }
+        _ => Err(format!("unknown tile '{tile}'")),
+    })?;
 
-    for (y, line) in input.lines().enumerate() {
-        // If line is empty, go the instructions parsing part
-        if line.is_empty() {
-            grid_completed = true
-        } else if !grid_completed {
-            // Parsing the line
-            grid.push(
-                line.chars()
-                    .enumerate()
-                    .map(|(x, tile)| match tile {
-                        '#' => MapTile::Wall,
-                        '.' => MapTile::Empty,
-                        'O' => MapTile::Box,
-                        '@' => {
-                            robot_positions = Coordinates::new(x as i64, y as i64);
-                            MapTile::Empty
-                        }
-                        _ => unreachable!(),
-                    })
-                    .collect(),
-            )
-        } else {
-            instructions.append(&mut line.chars().map(From::from).collect());
+    let mut instructions: Vec<Instruction> = vec![];
+    for (first_line, section) in sections.iter().skip(1) {
+        for (y, line) in section.lines().enumerate() {
+            for (x, instruction) in line.chars().enumerate() {
+                instructions.push(
+                    Instruction::try_from(instruction)
+                        .map_err(|message| ParseError::at_line(first_line + y, x, line, message))?,
+                );
+            }
         }
     }
 
-    Warehouse {
-        grid: Grid(grid),
+    let Some(robot_positions) = robot_positions else {
+        return Err(ParseError::at_line(
+            0,
+            0,
+            input.lines().next().unwrap_or_default(),
+            "missing robot '@'",
+        ));
+    };
+
+    Ok(Warehouse {
+        grid,
         robot_positions,
         instructions,
-    }
+    })
 }
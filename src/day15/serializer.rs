@@ -0,0 +1,15 @@
+use crate::day15::models::warehouse::Warehouse;
+use crate::generate::lines;
+
+/// Moves are given on lines of 1000 characters
+const MOVES_PER_LINE: usize = 1000;
+
+pub fn serialize_input(warehouse: &Warehouse) -> String {
+    let instructions = warehouse
+        .instructions
+        .chunks(MOVES_PER_LINE)
+        .map(|chunk| chunk.iter().map(|instruction| char::from(*instruction)).collect());
+
+    // The warehouse is displayed in the input format
+    warehouse.to_string() + "\n" + &lines(instructions)
+}
@@ -1,14 +1,24 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day15::Day15;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day15");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day15::default(), INPUT_EXAMPLE, 10092);
+    test_part_one_common::<Day15>(INPUT_EXAMPLE, 10092);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day15::default(), INPUT_EXAMPLE, 9021);
+    test_part_two_common::<Day15>(INPUT_EXAMPLE, 9021);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day15>("####\n#@.#\n####\n\n<>x^\n", 5, 3);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day15>(15);
 }
@@ -0,0 +1,61 @@
+use colored::Colorize;
+
+use crate::day15::models::big_warehouse::{BigMapTile, BigWarehouse};
+use crate::day15::models::warehouse::{MapTile, Warehouse};
+use crate::visualize::render_grid;
+
+fn render_warehouse(warehouse: &Warehouse, header: &str) -> String {
+    let frame = render_grid(&warehouse.grid, |position, tile| {
+        if position == warehouse.robot_positions {
+            "@".red().bold()
+        } else {
+            match tile {
+                MapTile::Wall => "#".bold(),
+                MapTile::Box => "O".yellow(),
+                MapTile::Empty => ".".dimmed(),
+            }
+        }
+    });
+    format!("{header}\n{frame}")
+}
+
+fn render_big_warehouse(warehouse: &BigWarehouse, header: &str) -> String {
+    let frame = render_grid(&warehouse.grid, |position, tile| {
+        if position == warehouse.robot_positions {
+            "@".red().bold()
+        } else {
+            match tile {
+                BigMapTile::Wall => "#".bold(),
+                BigMapTile::LeftBox => "[".yellow(),
+                BigMapTile::RightBox => "]".yellow(),
+                BigMapTile::Empty => ".".dimmed(),
+            }
+        }
+    });
+    format!("{header}\n{frame}")
+}
+
+/// Show the robot pushing boxes in the warehouse and then in the big warehouse, one frame per move
+pub fn frames(warehouse: &Warehouse) -> impl Iterator<Item = String> + '_ {
+    let moves = warehouse.instructions.len();
+
+    let mut small = warehouse.clone();
+    let small_frames = std::iter::once(render_warehouse(&small, "initial warehouse")).chain(
+        warehouse.instructions.iter().enumerate().map(move |(i, instruction)| {
+            small.move_robot(instruction);
+            let header = format!("move {}/{moves}: {}", i + 1, instruction.get_direction());
+            render_warehouse(&small, &header)
+        }),
+    );
+
+    let mut big = BigWarehouse::from(warehouse);
+    let big_frames = std::iter::once(render_big_warehouse(&big, "initial big warehouse")).chain(
+        warehouse.instructions.iter().enumerate().map(move |(i, instruction)| {
+            big.move_robot(instruction);
+            let header = format!("big warehouse move {}/{moves}: {}", i + 1, instruction.get_direction());
+            render_big_warehouse(&big, &header)
+        }),
+    );
+
+    small_frames.chain(big_frames)
+}
@@ -0,0 +1,19 @@
+use crate::generate::{lines, maze, Rng};
+use crate::grid::Coordinates;
+
+/// Maze with a few loops so that there are several best paths, the real input is 141 tiles wide.
+///
+/// Like in the real input, the reindeer starts in the bottom left corner and the end tile is in the
+/// top right corner.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    // Smaller mazes have a single floor tile, the start and the end would be on the same tile
+    let side = size.unwrap_or(141).max(5);
+    let walls = maze(rng, side, side, 0.1);
+
+    let mut map = walls.map(|wall| if *wall { '#' } else { '.' });
+    let (width, height) = (map.width(), map.height());
+    map[Coordinates::new(1, height - 2)] = 'S';
+    map[Coordinates::new(width - 2, 1)] = 'E';
+
+    lines(map.rows().map(|row| row.iter().collect()))
+}
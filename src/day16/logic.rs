@@ -1,14 +1,18 @@
-// use colored::Colorize;
+use colored::Colorize;
 use hashbrown::HashSet;
+use petgraph::dot::Dot;
 use petgraph::graph::Graph;
+use petgraph::visit::EdgeRef;
 
-// use petgraph::dot::Dot;
-use crate::day16::astar::multi_astar;
 use crate::day16::models::{Map, Tile};
+use crate::grid::Coordinates;
+use crate::logging::{enabled, trace, Level};
+use crate::pathfinding::dijkstra;
 
-pub fn prepare_data(map: &Map) -> (i32, HashSet<(usize, usize)>) {
-    let width = map.grid[0].len();
-    let height = map.grid.len();
+/// Lowest score and tiles on the best paths, `None` if the end cannot be reached
+pub fn prepare_data(map: &Map) -> Option<(i32, HashSet<Coordinates>)> {
+    let width = map.grid.width();
+    let height = map.grid.height();
 
     // Create the graph
     let mut graph = Graph::new_undirected();
@@ -41,42 +45,46 @@ pub fn prepare_data(map: &Map) -> (i32, HashSet<(usize, usize)>) {
     }
 
     // Display the graph
-    // println!("{:?}", Dot::with_config(&graph, &[]));
+    trace!("{:?}", Dot::with_config(&graph, &[]));
 
     // Get starting en ending node
-    let start = hor_nodes[map.start.0][map.start.1];
-    let hor_end = hor_nodes[map.end.0][map.end.1];
-    let ver_end = ver_nodes[map.end.0][map.end.1];
+    let start = hor_nodes[map.start.y][map.start.x];
+    let hor_end = hor_nodes[map.end.y][map.end.x];
+    let ver_end = ver_nodes[map.end.y][map.end.x];
 
     // Compute the shortest path and every nodes in it
-    let shortest_paths = multi_astar(
-        &graph,
+    let search = dijkstra(
         start,
-        |finish| finish == hor_end || finish == ver_end,
-        |e| *e.weight(),
-    )
-    .unwrap();
+        |node| graph.edges(*node).map(|edge| (edge.target(), *edge.weight())),
+        |node| *node == hor_end || *node == ver_end,
+    );
+    let end = search.goal?;
+    let shortest_paths = (search.goal_cost()?, search.nodes_on_optimal_paths(end));
 
     // Display the result
-    // for (i, line) in map.grid.iter().enumerate() {
-    //     for (j, block) in line.iter().enumerate() {
-    //         if shortest_paths.1.contains(&hor_nodes[i][j]) || shortest_paths.1.contains(&ver_nodes[i][j]) {
-    //             print!("{}", "O".green().bold());
-    //         } else {
-    //             match block {
-    //                 Tile::Floor => print!("."),
-    //                 Tile::Wall => print!("#")
-    //             }
-    //         }
-    //     }
-    //     println!();
-    // }
+    if enabled(Level::Trace) {
+        let mut display = String::new();
+        for (i, line) in map.grid.rows().enumerate() {
+            for (j, block) in line.iter().enumerate() {
+                if shortest_paths.1.contains(&hor_nodes[i][j]) || shortest_paths.1.contains(&ver_nodes[i][j]) {
+                    display.push_str(&"O".green().bold().to_string());
+                } else {
+                    match block {
+                        Tile::Floor => display.push('.'),
+                        Tile::Wall => display.push('#'),
+                    }
+                }
+            }
+            display.push('\n');
+        }
+        trace!("best paths:\n{display}");
+    }
 
     let mut paths = HashSet::new();
     for node in &shortest_paths.1 {
         let cost = &graph[*node];
-        paths.insert((cost.0, cost.1));
+        paths.insert(Coordinates::new(cost.1, cost.0));
     }
 
-    (shortest_paths.0, paths)
+    Some((shortest_paths.0, paths))
 }
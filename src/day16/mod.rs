@@ -1,38 +1,83 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
+mod visualization;
 
-mod astar;
 #[cfg(test)]
 mod tests;
 
 use hashbrown::HashSet;
 
+use crate::day16::generator::generate;
 use crate::day16::logic::prepare_data;
-use crate::day16::models::Map;
+pub use crate::day16::models::Map;
 use crate::day16::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day16::serializer::serialize_input;
+use crate::day16::visualization::{frames, picture};
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::{Coordinates, Grid};
+use crate::image::Color;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day16 {
-    parsed_data: Option<Map>,
-    prepared_data: Option<(i32, HashSet<(usize, usize)>)>,
-}
+pub struct Day16;
 
 impl AdventSolution for Day16 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Map;
+    type Prepared = Option<(i32, HashSet<Coordinates>)>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(16))
+    }
+
+    fn serialize(map: &Self::Parsed) -> String {
+        serialize(map)
+    }
+
+    fn prepare(map: Self::Parsed) -> Self::Prepared {
+        prepare_data(&map)
+    }
+
+    // There is no answer if the end of the maze cannot be reached
+    fn solve_part_one(prepared: &Self::Prepared) -> Answer {
+        prepared.as_ref().map(|(score, _)| *score).into()
+    }
+
+    fn solve_part_two(prepared: &Self::Prepared) -> Answer {
+        prepared.as_ref().map(|(_, best_tiles)| best_tiles.len()).into()
     }
 
-    fn prepare(&mut self) {
-        self.prepared_data = Some(prepare_data(self.parsed_data.as_ref().unwrap()));
+    fn visualize(map: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(frames(map)))
     }
 
-    fn solve_part_one(&self) -> i128 {
-        self.prepared_data.as_ref().unwrap().0 as i128
+    fn export(map: &Self::Parsed, _step: Option<usize>) -> Option<Grid<Color>> {
+        Some(picture(map))
     }
 
-    fn solve_part_two(&self) -> i128 {
-        self.prepared_data.as_ref().unwrap().1.len() as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the maze
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Day16::parse(input.to_string())
+}
+
+/// Write the maze in the input format
+pub fn serialize(map: &Map) -> String {
+    serialize_input(map)
+}
+
+/// Lowest score a reindeer can get, `None` if the end cannot be reached
+pub fn part_one(map: &Map) -> Option<i32> {
+    Some(prepare_data(map)?.0)
+}
+
+/// Number of tiles on at least one of the best paths, `None` if the end cannot be reached
+pub fn part_two(map: &Map) -> Option<usize> {
+    Some(prepare_data(map)?.1.len())
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::grid::{Coordinates, Grid};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Tile {
     Floor,
@@ -17,24 +19,24 @@ impl Display for Tile {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Map {
-    pub grid: Vec<Vec<Tile>>,
-    pub start: (usize, usize),
-    pub end: (usize, usize),
+    pub grid: Grid<Tile>,
+    pub start: Coordinates,
+    pub end: Coordinates,
 }
 
 impl Display for Map {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (i, line) in self.grid.iter().enumerate() {
-            for (j, tile) in line.iter().enumerate() {
-                if self.start.0 == i && self.start.1 == j {
-                    write!(f, "S")?;
-                } else if self.end.0 == i && self.end.1 == j {
-                    write!(f, "E")?;
-                } else {
-                    write!(f, "{tile}")?;
-                }
+        for (position, tile) in self.grid.iter() {
+            if position == self.start {
+                write!(f, "S")?;
+            } else if position == self.end {
+                write!(f, "E")?;
+            } else {
+                write!(f, "{tile}")?;
+            }
+            if position.x + 1 == self.grid.width() {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
         Ok(())
     }
@@ -1,31 +1,33 @@
 use crate::day16::models::{Map, Tile};
+use crate::errors::ParseError;
+use crate::grid::Grid;
 
-pub fn parse_input(input: String) -> Map {
+pub fn parse_input(input: String) -> Result<Map, ParseError> {
     // We won't need to add a border here since the map already has one.
-    let mut grid: Vec<Vec<Tile>> = vec![];
-    let mut start = (0, 0);
-    let mut end = (0, 0);
+    let mut start = None;
+    let mut end = None;
 
-    for (i, line) in input.lines().enumerate() {
-        grid.push(
-            line.chars()
-                .enumerate()
-                .map(|(j, tile)| match tile {
-                    '#' => Tile::Wall,
-                    '.' => Tile::Floor,
-                    'S' => {
-                        start = (i, j);
-                        Tile::Floor
-                    }
-                    'E' => {
-                        end = (i, j);
-                        Tile::Floor
-                    }
-                    _ => unreachable!(),
-                })
-                .collect(),
-        )
-    }
+    let grid = Grid::parse(&input, |position, tile| match tile {
+        '#' => Ok(Tile::Wall),
+        '.' => Ok(Tile::Floor),
+        'S' => {
+            start = Some(position);
+            Ok(Tile::Floor)
+        }
+        'E' => {
+            end = Some(position);
+            Ok(Tile::Floor)
+        }
+        _ => Err(format!("unknown tile '{tile}'")),
+    })?;
 
-    Map { grid, start, end }
+    let first_line = input.lines().next().unwrap_or_default();
+    let Some(start) = start else {
+        return Err(ParseError::at_line(0, 0, first_line, "missing start tile 'S'"));
+    };
+    let Some(end) = end else {
+        return Err(ParseError::at_line(0, 0, first_line, "missing end tile 'E'"));
+    };
+
+    Ok(Map { grid, start, end })
 }
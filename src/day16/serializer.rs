@@ -0,0 +1,6 @@
+use crate::day16::models::Map;
+
+pub fn serialize_input(map: &Map) -> String {
+    // The map is displayed in the input format
+    map.to_string()
+}
@@ -1,17 +1,33 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day16::Day16;
+use crate::models::Answer;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day16");
 static INPUT_EXAMPLE_2: &str = include_str!("../../input_examples/day16_2");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day16::default(), INPUT_EXAMPLE, 7036);
-    test_part_one_common(Day16::default(), INPUT_EXAMPLE_2, 11048);
+    test_part_one_common::<Day16>(INPUT_EXAMPLE, 7036);
+    test_part_one_common::<Day16>(INPUT_EXAMPLE_2, 11048);
+
+    // An empty line at the end of the input is ignored
+    test_part_one_common::<Day16>(&format!("{INPUT_EXAMPLE}\n"), 7036);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day16::default(), INPUT_EXAMPLE, 45);
-    test_part_two_common(Day16::default(), INPUT_EXAMPLE_2, 64);
+    test_part_two_common::<Day16>(INPUT_EXAMPLE, 45);
+    test_part_two_common::<Day16>(INPUT_EXAMPLE_2, 64);
+}
+
+#[test]
+fn test_unreachable_end() {
+    let input = "#####\n#S#E#\n#####\n";
+    test_part_one_common::<Day16>(input, Answer::NotApplicable);
+    test_part_two_common::<Day16>(input, Answer::NotApplicable);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day16>(16);
 }
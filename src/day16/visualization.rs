@@ -0,0 +1,87 @@
+use colored::Colorize;
+use hashbrown::HashSet;
+
+use crate::day16::logic::prepare_data;
+use crate::day16::models::{Map, Tile};
+use crate::grid::{Coordinates, Direction, Grid};
+use crate::image::Color;
+use crate::pathfinding::dijkstra;
+use crate::visualize::render_grid;
+
+fn render_map(map: &Map, path: &HashSet<Coordinates>, reindeer: Option<Coordinates>, header: &str) -> String {
+    let frame = render_grid(&map.grid, |position, tile| {
+        if Some(position) == reindeer {
+            "@".red().bold()
+        } else if path.contains(&position) {
+            "O".green().bold()
+        } else if position == map.start {
+            "S".magenta()
+        } else if position == map.end {
+            "E".magenta()
+        } else {
+            match tile {
+                Tile::Floor => ".".dimmed(),
+                Tile::Wall => "#".bold(),
+            }
+        }
+    });
+    format!("{header}\n{frame}")
+}
+
+/// Show the reindeer following one of the best paths, then every tile that is on a best path
+pub fn frames(map: &Map) -> impl Iterator<Item = String> + '_ {
+    // The reindeer starts facing east, moving forward costs 1 and turning costs 1000
+    let search = dijkstra(
+        (map.start, Direction::Right),
+        |(position, direction): &(Coordinates, Direction)| {
+            let forward = *position + *direction;
+            [
+                (map.grid[forward] == Tile::Floor).then_some(((forward, *direction), 1)),
+                Some(((*position, direction.turn_left()), 1000)),
+                Some(((*position, direction.turn_right()), 1000)),
+            ]
+            .into_iter()
+            .flatten()
+        },
+        |(position, _)| *position == map.end,
+    );
+
+    let (path, best_tiles, score) = match (search.goal, search.goal_cost()) {
+        (Some(goal), Some(score)) => {
+            let path = search.path_to(goal).unwrap_or_default();
+            let best_tiles: HashSet<_> = search
+                .nodes_on_optimal_paths(goal)
+                .into_iter()
+                .map(|(position, _)| position)
+                .collect();
+            (path, best_tiles, score)
+        }
+        _ => (vec![], HashSet::new(), 0),
+    };
+
+    let mut walked = HashSet::new();
+    let walk = path.into_iter().map(move |(position, direction)| {
+        walked.insert(position);
+        render_map(map, &walked, Some(position), &format!("walking {direction}"))
+    });
+
+    let header = format!("score {score}, {} tiles on the best paths", best_tiles.len());
+    walk.chain(std::iter::once(render_map(map, &best_tiles, None, &header)))
+}
+
+/// Picture of every tile that is on one of the best paths through the maze
+pub fn picture(map: &Map) -> Grid<Color> {
+    let best_tiles = prepare_data(map).map(|(_, best_tiles)| best_tiles).unwrap_or_default();
+
+    let mut image = map.grid.map(|tile| match tile {
+        Tile::Floor => Color::DARK_GREY,
+        Tile::Wall => Color::GREY,
+    });
+    for position in best_tiles {
+        image[position] = Color::GREEN;
+    }
+    image[map.start] = Color::MAGENTA;
+    image[map.end] = Color::MAGENTA;
+
+    image
+}
@@ -0,0 +1,30 @@
+use itertools::Itertools;
+
+use crate::generate::Rng;
+
+/// Program shaped like the real ones: each loop iteration prints a value computed from the last 3
+/// bits of A and then shifts A by 3 bits, until A is 0.
+///
+/// `size` is the number of values printed by the program, the real inputs print 16 values.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let outputs = size.unwrap_or(16).clamp(1, 21) as u32;
+
+    // bst A, bxl k, cdv B then bxl k and bxc in any order
+    let mut mix = [vec![1, rng.range(0..8)], vec![4, rng.range(0..8)]];
+    rng.shuffle(&mut mix);
+    let program = [
+        vec![2, 4, 1, rng.range(0..8), 7, 5],
+        mix.concat(),
+        vec![0, 3, 5, 5, 3, 0],
+    ]
+    .concat();
+
+    // A has exactly `outputs` octal digits
+    let lowest = 8u64.pow(outputs - 1);
+    let a = lowest + rng.below(8u64.pow(outputs) - lowest);
+
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program.iter().join(",")
+    )
+}
@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use crate::day17::models::{Instruction, Operand, Program, Register};
+use crate::logging::{debug, trace};
 
 impl Program {
     /// Run a program until it stops and return what was eventually printed
@@ -36,17 +37,14 @@ impl Program {
     }
 }
 
-pub fn solve_part_one(program: &Program) -> u32 {
+pub fn solve_part_one(program: &Program) -> String {
     // Make a program that can be modified
     let mut program = program.clone();
 
     // run it
     let result = program.run();
 
-    // Print the result since we cannot return strings as result
-    println!("{}", result.iter().map(|a| a.to_string()).join(","));
-
-    0
+    result.iter().map(|a| a.to_string()).join(",")
 }
 
 impl Program {
@@ -69,7 +67,7 @@ impl Program {
                         Operand::Literal(value) => shift += *value as u64,
                         Operand::Register(_) => {
                             // We cannot allow modifying A by a non-literal value
-                            println!("Cannot modify A with a non literal value!");
+                            debug!("Cannot modify A with a non literal value!");
                             return None;
                         }
                     }
@@ -78,22 +76,20 @@ impl Program {
                     if pos != self.parsed_instructions.len() - 1 {
                         // We cannot admit a jump instruction that is not the last instruction of
                         // the program.
-                        println!("Only one jump at the end is accepted");
+                        debug!("Only one jump at the end is accepted");
                         return None;
                     }
                     if operand != &Operand::Literal(0) {
                         // We do not support not jumping to the beginning
-                        println!("Jump operand must be 0");
+                        debug!("Jump operand must be 0");
                         return None;
                     }
                 }
-                Instruction::Out(_) => {
-                    if pos != self.parsed_instructions.len() - 2 {
-                        // We cannot admit an out instruction that is not the penultimate
-                        // instruction of the program.
-                        println!("Only one out operand is accepted");
-                        return None;
-                    }
+                Instruction::Out(_) if pos != self.parsed_instructions.len() - 2 => {
+                    // We cannot admit an out instruction that is not the penultimate
+                    // instruction of the program.
+                    debug!("Only one out operand is accepted");
+                    return None;
                 }
                 _ => {}
             }
@@ -103,14 +99,14 @@ impl Program {
         if let Instruction::Out(operand) = &self.parsed_instructions[self.parsed_instructions.len() - 2] {
             print_a = operand == &Operand::Register(Register::A);
         } else {
-            println!("Missing last out instruction");
+            debug!("Missing last out instruction");
             return None;
         }
         if !matches!(
             self.parsed_instructions[self.parsed_instructions.len() - 1],
             Instruction::Jnz(Operand::Literal(0))
         ) {
-            println!("Missing last jump instruction");
+            debug!("Missing last jump instruction");
             return None;
         }
 
@@ -118,9 +114,11 @@ impl Program {
     }
 }
 
-pub fn solve_part_two(program: &Program) -> u64 {
+/// Return the lowest value of A for which the program prints itself, or None if our algorithm
+/// cannot find it.
+pub fn solve_part_two(program: &Program) -> Option<u64> {
     // Get the max_shift of a for the bruteforce
-    let (shift, print_a) = program.is_solvable().unwrap();
+    let (shift, print_a) = program.is_solvable()?;
 
     // Store values of A to test next
     let mut to_test = vec![];
@@ -160,7 +158,7 @@ pub fn solve_part_two(program: &Program) -> u64 {
                 bruteforce_program.registers.a <<= shift;
             }
 
-            // println!("target:{}, i:{}, a:{:#b}", target, i, bruteforce_program.registers.a);
+            trace!("target:{}, i:{}, a:{:#b}", target, i, bruteforce_program.registers.a);
 
             // Run the bruteforce program
             let result = bruteforce_program.run();
@@ -169,8 +167,9 @@ pub fn solve_part_two(program: &Program) -> u64 {
             if result[0] == target {
                 // We found a new value of a that is compatible, Add it to the stack
 
-                // i cannot be null if a is null
-                if test_a == 0 && i == 0 {
+                // i cannot be null if a is null, unless a is printed: in this case, the last
+                // printed value is always computed from the final null value of a
+                if test_a == 0 && i == 0 && !print_a {
                     continue
                 }
 
@@ -183,14 +182,8 @@ pub fn solve_part_two(program: &Program) -> u64 {
                         new_a <<= shift;
                     }
 
-                    // Try it
-                    // let mut test_program = program.clone();
-                    // test_program.registers.a = new_a;
-                    // let result = test_program.run();
-                    // println!("{}", result.iter().map(|a| a.to_string()).join(","));
-
                     // return it
-                    return new_a;
+                    return Some(new_a);
                 }
 
                 // If not, add it to the stack
@@ -199,5 +192,5 @@ pub fn solve_part_two(program: &Program) -> u64 {
         }
     }
 
-    unreachable!();
+    None
 }
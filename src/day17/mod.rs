@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day17::generator::generate;
 use crate::day17::logic::{solve_part_one, solve_part_two};
-use crate::day17::models::Program;
+pub use crate::day17::models::Program;
 use crate::day17::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day17::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day17 {
-    parsed_data: Option<Program>,
-}
+pub struct Day17;
 
 impl AdventSolution for Day17 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Program;
+    type Prepared = Program;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(17))
+    }
+
+    fn serialize(program: &Self::Parsed) -> String {
+        serialize(program)
+    }
+
+    fn prepare(program: Self::Parsed) -> Self::Prepared {
+        program
+    }
+
+    fn solve_part_one(program: &Self::Prepared) -> Answer {
+        part_one(program).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(program: &Self::Prepared) -> Answer {
+        part_two(program).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the registers and the program
+pub fn parse(input: &str) -> Result<Program, ParseError> {
+    Day17::parse(input.to_string())
+}
+
+/// Write the registers and the program in the input format
+pub fn serialize(program: &Program) -> String {
+    serialize_input(program)
+}
+
+/// Output of the program
+pub fn part_one(program: &Program) -> String {
+    solve_part_one(program)
+}
+
+/// Lowest value of register A that makes the program output itself
+pub fn part_two(program: &Program) -> Option<u64> {
+    solve_part_two(program)
+}
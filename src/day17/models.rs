@@ -1,5 +1,3 @@
-use itertools::Itertools;
-
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Register {
     A,
@@ -18,17 +16,17 @@ impl Operand {
         Self::Literal(value)
     }
 
-    pub fn new_combo(value: u8) -> Self {
+    pub fn new_combo(value: u8) -> Result<Self, String> {
         match value {
-            0 => Self::Literal(0),
-            1 => Self::Literal(1),
-            2 => Self::Literal(2),
-            3 => Self::Literal(3),
-            4 => Self::Register(Register::A),
-            5 => Self::Register(Register::B),
-            6 => Self::Register(Register::C),
-            7 => unreachable!("Combo operand cannot be 7"),
-            _ => unreachable!(),
+            0 => Ok(Self::Literal(0)),
+            1 => Ok(Self::Literal(1)),
+            2 => Ok(Self::Literal(2)),
+            3 => Ok(Self::Literal(3)),
+            4 => Ok(Self::Register(Register::A)),
+            5 => Ok(Self::Register(Register::B)),
+            6 => Ok(Self::Register(Register::C)),
+            7 => Err("combo operand cannot be 7".to_string()),
+            _ => Err(format!("invalid combo operand {value}")),
         }
     }
 }
@@ -46,18 +44,18 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn new(opcode: u8, operand: u8) -> Self {
-        match opcode {
-            0 => Self::Adv(Operand::new_combo(operand)),
+    pub fn new(opcode: u8, operand: u8) -> Result<Self, String> {
+        Ok(match opcode {
+            0 => Self::Adv(Operand::new_combo(operand)?),
             1 => Self::Bxl(Operand::new_literal(operand)),
-            2 => Self::Bst(Operand::new_combo(operand)),
+            2 => Self::Bst(Operand::new_combo(operand)?),
             3 => Self::Jnz(Operand::new_literal(operand)),
             4 => Self::Bxc,
-            5 => Self::Out(Operand::new_combo(operand)),
-            6 => Self::Bdv(Operand::new_combo(operand)),
-            7 => Self::Cdv(Operand::new_combo(operand)),
-            _ => unreachable!(),
-        }
+            5 => Self::Out(Operand::new_combo(operand)?),
+            6 => Self::Bdv(Operand::new_combo(operand)?),
+            7 => Self::Cdv(Operand::new_combo(operand)?),
+            _ => return Err(format!("invalid opcode {opcode}")),
+        })
     }
 }
 
@@ -95,13 +93,7 @@ pub struct Program {
 }
 
 impl Program {
-    pub fn new(a: u64, b: u64, c: u64, raw_instructions: Vec<u8>) -> Self {
-        let parsed_instructions = raw_instructions
-            .iter()
-            .tuples()
-            .map(|(opcode, operand)| Instruction::new(*opcode, *operand))
-            .collect();
-
+    pub fn new(a: u64, b: u64, c: u64, raw_instructions: Vec<u8>, parsed_instructions: Vec<Instruction>) -> Self {
         Self {
             registers: Registers::new(a, b, c),
             instruction_pointer: 0,
@@ -1,54 +1,72 @@
-use nom::branch::alt;
+use itertools::Itertools;
 use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, u64 as u64_parser, u8 as u8_parser};
-use nom::combinator::{map, opt};
-use nom::multi::{many1, separated_list1};
-use nom::sequence::tuple;
+use nom::character::complete::one_of;
+use nom::combinator::{map, verify};
+use nom::sequence::{pair, preceded, separated_pair, tuple};
 use nom::{IResult, Parser};
 
-use crate::day17::models::Program;
+use crate::day17::models::{Instruction, Program};
+use crate::errors::ParseError;
+use crate::parsing::{blank_lines, finish, key_value, line, list, unsigned};
 
 fn parse_register(input: &str) -> IResult<&str, u64> {
-    map(
-        tuple((
-            tag("Register "),
-            alt((tag("A"), tag("B"), tag("C"))),
-            tag(": "),
-            u64_parser,
-            line_ending,
-        )),
-        |(_, _, _, value, _)| value,
-    )
+    line(preceded(
+        pair(tag("Register "), one_of("ABC")),
+        preceded(tag(": "), unsigned),
+    ))
     .parse(input)
 }
 
 fn parse_instructions(input: &str) -> IResult<&str, Vec<u8>> {
-    map(
-        tuple((tag("Program: "), separated_list1(tag(","), u8_parser), opt(line_ending))),
-        |(_, instructions, _)| instructions,
-    )
+    line(key_value(
+        "Program",
+        ": ",
+        list(",", verify(unsigned, |value| *value < 8)),
+    ))
     .parse(input)
 }
 
-fn parse_program(input: &str) -> IResult<&str, Program> {
+fn parse_program(input: &str) -> IResult<&str, (u64, u64, u64, Vec<u8>)> {
     map(
-        tuple((
-            parse_register,
-            parse_register,
-            parse_register,
-            many1(line_ending),
+        separated_pair(
+            tuple((parse_register, parse_register, parse_register)),
+            blank_lines,
             parse_instructions,
-        )),
-        |(a, b, c, _, instructions)| Program::new(a, b, c, instructions),
+        ),
+        |((a, b, c), instructions)| (a, b, c, instructions),
     )
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Program {
-    let (res, program) = parse_program.parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
+pub fn parse_input(input: String) -> Result<Program, ParseError> {
+    let (a, b, c, instructions) = finish(&input, parse_program.parse(&input))?;
+
+    // Errors on instructions are reported on the program line
+    let (y, line) = input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.starts_with("Program: "))
+        .unwrap_or_default();
+
+    if instructions.len() % 2 != 0 {
+        return Err(ParseError::at_line(
+            y,
+            line.len(),
+            line,
+            "missing operand for the last instruction",
+        ));
     }
 
-    program
+    let parsed_instructions = instructions
+        .iter()
+        .tuples()
+        .enumerate()
+        .map(|(i, (opcode, operand))| {
+            // Each instruction takes 4 characters: "o,o,"
+            Instruction::new(*opcode, *operand)
+                .map_err(|message| ParseError::at_line(y, "Program: ".len() + 4 * i + 2, line, message))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Program::new(a, b, c, instructions, parsed_instructions))
 }
@@ -0,0 +1,14 @@
+use itertools::Itertools;
+
+use crate::day17::models::Program;
+
+pub fn serialize_input(program: &Program) -> String {
+    let registers = &program.registers;
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        registers.a,
+        registers.b,
+        registers.c,
+        program.raw_instructions.iter().join(",")
+    )
+}
@@ -1,4 +1,7 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use itertools::Itertools;
+
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
+use crate::day17;
 use crate::day17::Day17;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day17");
@@ -6,10 +9,45 @@ static INPUT_EXAMPLE_2: &str = include_str!("../../input_examples/day17_2");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day17::default(), INPUT_EXAMPLE, 0);
+    test_part_one_common::<Day17>(INPUT_EXAMPLE, "4,6,3,5,6,3,5,2,1,0");
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day17::default(), INPUT_EXAMPLE_2, 117440);
+    test_part_two_common::<Day17>(INPUT_EXAMPLE_2, 117440);
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,7\n";
+    test_parse_error_common::<Day17>(input, 5, 16);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day17>(17);
+}
+
+#[test]
+fn test_part_two_outputs_itself() {
+    // The example prints A itself, the second program prints a value computed from B
+    let program_printing_b =
+        "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,3,7,5,0,3,1,5,4,4,5,5,3,0\n";
+    for input in [INPUT_EXAMPLE_2, program_printing_b] {
+        let mut program = day17::parse(input).unwrap();
+        program.registers.a = day17::part_two(&program).unwrap();
+        assert_eq!(day17::part_one(&program), program.raw_instructions.iter().join(","));
+    }
+}
+
+#[test]
+fn test_part_two_is_the_lowest_value() {
+    // When A is printed, the last output is computed from the final null value of A so the search
+    // must accept 0 as the first three bits
+    let mut program = day17::parse(INPUT_EXAMPLE_2).unwrap();
+    let expected = program.raw_instructions.iter().join(",");
+    for a in 0..117440 {
+        program.registers.a = a;
+        assert_ne!(day17::part_one(&program), expected, "{a} also outputs the program");
+    }
 }
@@ -0,0 +1,47 @@
+use crate::generate::{lines, Rng};
+use crate::grid::{Coordinates, Grid};
+use crate::pathfinding::bfs;
+
+/// Size of the real memory space, smaller ones are only used by the example
+const SIDE: usize = 71;
+
+/// Number of bytes that fell when the path is searched in the first part
+const FALLEN: usize = 1024;
+
+/// Return true if the exit can be reached once `bytes` fell
+fn is_reachable(bytes: &[Coordinates]) -> bool {
+    let mut damaged = Grid::new(SIDE, SIDE, false);
+    for byte in bytes {
+        damaged[*byte] = true;
+    }
+
+    let exit = Coordinates::new(SIDE - 1, SIDE - 1);
+    bfs(
+        Coordinates::new(0, 0),
+        |node| damaged.neighbours(*node).filter(|next| !damaged[*next]),
+        |node| *node == exit,
+    )
+    .goal
+    .is_some()
+}
+
+/// Positions of the bytes falling in the memory space, the real input has 3450 bytes.
+///
+/// The exit can still be reached after the first 1024 bytes fell, as required by the first part.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(3450).clamp(FALLEN + 1, SIDE * SIDE - 2);
+
+    // The start and the exit are never corrupted
+    let mut bytes: Vec<_> = (0..SIDE * SIDE)
+        .map(|i| Coordinates::new(i % SIDE, i / SIDE))
+        .filter(|byte| *byte != Coordinates::new(0, 0) && *byte != Coordinates::new(SIDE - 1, SIDE - 1))
+        .collect();
+    loop {
+        rng.shuffle(&mut bytes);
+        if is_reachable(&bytes[..FALLEN]) {
+            break;
+        }
+    }
+
+    lines(bytes[..count].iter().map(|byte| format!("{},{}", byte.x, byte.y)))
+}
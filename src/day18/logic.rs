@@ -1,11 +1,39 @@
-use crate::day18::astar::astar;
 use crate::day18::models::Corruption;
+use crate::grid::{Coordinates, Grid};
+use crate::pathfinding::astar;
+
+/// Length of the shortest path from the top left corner to the bottom right one once `to_simulate`
+/// bytes fell, `None` if there is no path
+fn shortest_path(corruption_map: &Corruption, to_simulate: usize) -> Option<usize> {
+    // Mark the damaged bytes on a grid to improve search speed
+    let mut damaged = Grid::new(corruption_map.size, corruption_map.size, false);
+    for byte in corruption_map.bytes.iter().take(to_simulate) {
+        damaged[Coordinates::from(*byte)] = true;
+    }
+
+    // Set start and end
+    let start = Coordinates::new(0, 0);
+    let target = Coordinates::new(corruption_map.size - 1, corruption_map.size - 1);
+
+    astar(
+        start,
+        |node| {
+            damaged
+                .neighbours(*node)
+                .filter(|next| !damaged[*next])
+                .map(|next| (next, 1))
+        },
+        |node| node.manhattan_distance(&target),
+        |node| *node == target,
+    )
+    .goal_cost()
+}
 
 pub fn solve_part_one(corruption_map: &Corruption) -> usize {
-    astar(corruption_map, corruption_map.to_simulate).unwrap()
+    shortest_path(corruption_map, corruption_map.to_simulate).unwrap()
 }
 
-pub fn solve_part_two(corruption_map: &Corruption) -> usize {
+pub fn solve_part_two(corruption_map: &Corruption) -> String {
     // We now that the corruption_map.to_simulate is valid, so we can use that as the start of our
     // dichotomy.
     let mut start = corruption_map.to_simulate;
@@ -18,7 +46,7 @@ pub fn solve_part_two(corruption_map: &Corruption) -> usize {
         let middle = (start + end) / 2;
 
         // Try the middle value
-        let is_possible = astar(corruption_map, middle).is_some();
+        let is_possible = shortest_path(corruption_map, middle).is_some();
 
         // Update the value depending on the result
         if is_possible {
@@ -30,5 +58,6 @@ pub fn solve_part_two(corruption_map: &Corruption) -> usize {
 
     // We take start that is equal to end - 1 because if we need to simulate i step to get a
     // failure, this is the i-1th byte that failed
-    corruption_map.bytes[start].0 * 100 + corruption_map.bytes[start].1
+    let (x, y) = corruption_map.bytes[start];
+    format!("{x},{y}")
 }
@@ -1,31 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
-mod astar;
 #[cfg(test)]
 mod tests;
 
+use crate::day18::generator::generate;
 use crate::day18::logic::{solve_part_one, solve_part_two};
-use crate::day18::models::Corruption;
+pub use crate::day18::models::Corruption;
 use crate::day18::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day18::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day18 {
-    parsed_data: Option<Corruption>,
-}
+pub struct Day18;
 
 impl AdventSolution for Day18 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Corruption;
+    type Prepared = Corruption;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(18))
+    }
+
+    fn serialize(corruption: &Self::Parsed) -> String {
+        serialize(corruption)
+    }
+
+    fn prepare(corruption: Self::Parsed) -> Self::Prepared {
+        corruption
+    }
+
+    fn solve_part_one(corruption: &Self::Prepared) -> Answer {
+        part_one(corruption).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(corruption: &Self::Prepared) -> Answer {
+        part_two(corruption).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the falling bytes
+pub fn parse(input: &str) -> Result<Corruption, ParseError> {
+    Day18::parse(input.to_string())
+}
+
+/// Write the falling bytes in the input format
+pub fn serialize(corruption: &Corruption) -> String {
+    serialize_input(corruption)
+}
+
+/// Minimum number of steps needed to reach the exit
+pub fn part_one(corruption: &Corruption) -> usize {
+    solve_part_one(corruption)
+}
+
+/// Coordinates `x,y` of the first byte that blocks the exit
+pub fn part_two(corruption: &Corruption) -> String {
+    solve_part_two(corruption)
+}
@@ -1,25 +1,15 @@
-use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, u128 as u128_parser};
-use nom::combinator::map;
-use nom::multi::many1;
-use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
 use crate::day18::models::Corruption;
+use crate::errors::ParseError;
+use crate::parsing::{finish, line, many1_complete, number_pair};
 
 fn parse_bytes(input: &str) -> IResult<&str, (usize, usize)> {
-    map(
-        tuple((u128_parser, tag(","), u128_parser, line_ending)),
-        |(x, _, y, _)| (x as usize, y as usize),
-    )
-    .parse(input)
+    line(number_pair(",")).parse(input)
 }
 
-pub fn parse_input(input: String) -> Corruption {
-    let (res, bytes) = many1(parse_bytes).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
+pub fn parse_input(input: String) -> Result<Corruption, ParseError> {
+    let bytes = finish(&input, many1_complete(parse_bytes).parse(&input))?;
 
-    Corruption::new(bytes)
+    Ok(Corruption::new(bytes))
 }
@@ -0,0 +1,7 @@
+use crate::day18::models::Corruption;
+use crate::generate::lines;
+
+pub fn serialize_input(corruption: &Corruption) -> String {
+    // The size of the memory space is not part of the input, it is deduced from the bytes
+    lines(corruption.bytes.iter().map(|(x, y)| format!("{x},{y}")))
+}
@@ -0,0 +1,45 @@
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+use crate::generate::{lines, word, Rng};
+
+/// Stripe colours of the towels
+const COLORS: &[u8] = b"wubrg";
+
+/// Available towel patterns followed by the designs to make, the real input has 447 patterns and
+/// 400 designs
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(400);
+
+    // Like in the real input, one of the colors has no single stripe towel so that some designs
+    // are impossible
+    let missing = *rng.choose(COLORS) as char;
+    let mut towels: HashSet<String> = COLORS
+        .iter()
+        .map(|color| (*color as char).to_string())
+        .filter(|towel| !towel.starts_with(missing))
+        .collect();
+    while towels.len() < 447 {
+        let length = rng.range(2..9);
+        towels.insert(word(rng, COLORS, length));
+    }
+    // Sort the set first so that the same seed always gives the same towels
+    let mut towels: Vec<_> = towels.into_iter().sorted().collect();
+    rng.shuffle(&mut towels);
+
+    let designs = (0..count).map(|_| {
+        let length = rng.range(20..61);
+        if rng.chance(0.5) {
+            // Designs made of towels can always be made
+            let mut design = String::new();
+            while design.len() < length {
+                design.push_str(rng.choose(&towels).as_str());
+            }
+            design
+        } else {
+            word(rng, COLORS, length)
+        }
+    });
+
+    towels.iter().join(", ") + "\n\n" + &lines(designs)
+}
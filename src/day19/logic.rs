@@ -2,6 +2,8 @@ use std::cmp::min;
 
 use hashbrown::HashSet;
 
+use crate::day19::models::Onsen;
+
 pub fn count_combinations_rec(
     available_towels: &HashSet<String>,
     design: &str,
@@ -42,3 +44,15 @@ pub fn count_combinations(available_towels: &HashSet<String>, design: &str, min_
 
     count_combinations_rec(available_towels, design, min_size, max_size, &mut cache, 0)
 }
+
+/// Number of ways to make each design of the onsen
+pub fn count_all_combinations(onsen: &Onsen) -> Vec<usize> {
+    let min_size = onsen.available_towels.iter().map(|a| a.len()).min().unwrap();
+    let max_size = onsen.available_towels.iter().map(|a| a.len()).max().unwrap();
+
+    onsen
+        .target_designs
+        .iter()
+        .map(|design| count_combinations(&onsen.available_towels_set, design, min_size, max_size))
+        .collect()
+}
@@ -1,44 +1,73 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
-use crate::day19::logic::count_combinations;
-use crate::day19::models::Onsen;
+use crate::day19::generator::generate;
+use crate::day19::logic::count_all_combinations;
+pub use crate::day19::models::Onsen;
 use crate::day19::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day19::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day19 {
-    parsed_data: Option<Onsen>,
-    counts: Vec<usize>,
-}
+pub struct Day19;
 
 impl AdventSolution for Day19 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Onsen;
+    type Prepared = Vec<usize>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(19))
     }
 
-    fn prepare(&mut self) {
-        let onsen = self.parsed_data.as_ref().unwrap();
+    fn serialize(onsen: &Self::Parsed) -> String {
+        serialize(onsen)
+    }
 
-        let min_size = onsen.available_towels.iter().map(|a| a.len()).min().unwrap();
-        let max_size = onsen.available_towels.iter().map(|a| a.len()).max().unwrap();
+    fn prepare(onsen: Self::Parsed) -> Self::Prepared {
+        prepare(&onsen)
+    }
 
-        self.counts = onsen
-            .target_designs
-            .iter()
-            .map(|design| count_combinations(&onsen.available_towels_set, design, min_size, max_size))
-            .collect();
+    fn solve_part_one(counts: &Self::Prepared) -> Answer {
+        part_one(counts).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        self.counts.iter().filter(|count| **count != 0).count() as i128
+    fn solve_part_two(counts: &Self::Prepared) -> Answer {
+        part_two(counts).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        self.counts.iter().copied().sum::<usize>() as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the towels and the designs
+pub fn parse(input: &str) -> Result<Onsen, ParseError> {
+    Day19::parse(input.to_string())
+}
+
+/// Write the towels and the designs in the input format
+pub fn serialize(onsen: &Onsen) -> String {
+    serialize_input(onsen)
+}
+
+/// Number of ways to make each design, shared by both parts
+pub fn prepare(onsen: &Onsen) -> Vec<usize> {
+    count_all_combinations(onsen)
+}
+
+/// Number of designs that can be made
+pub fn part_one(counts: &[usize]) -> usize {
+    counts.iter().filter(|count| **count != 0).count()
+}
+
+/// Total number of ways to make the designs
+pub fn part_two(counts: &[usize]) -> usize {
+    counts.iter().sum()
+}
@@ -1,18 +1,32 @@
 use crate::day19::models::Onsen;
+use crate::errors::ParseError;
 
-pub fn parse_input(input: String) -> Onsen {
+pub fn parse_input(input: String) -> Result<Onsen, ParseError> {
     let mut towels: Vec<String> = vec![];
     let mut designs: Vec<String> = vec![];
 
     for (i, line) in input.lines().enumerate() {
+        // Towels and designs are made of stripe colors
+        if let Some(x) = line.find(|c: char| !c.is_ascii_lowercase() && (i != 0 || !", ".contains(c))) {
+            return Err(ParseError::at_line(i, x, line, "expected a stripe color"));
+        }
+
         if i == 0 {
             // First line
             towels = line.split(", ").map(|towel| towel.to_string()).collect();
+            if let Some(x) = towels.iter().position(String::is_empty) {
+                let column = towels[..x].iter().map(|towel| towel.len() + 2).sum();
+                return Err(ParseError::at_line(i, column, line, "expected a towel"));
+            }
         } else if !line.is_empty() {
             // Skip empty lines
             designs.push(line.to_string());
         }
     }
 
-    Onsen::new(towels, designs)
+    if towels.is_empty() {
+        return Err(ParseError::at_line(0, 0, "", "expected a towel"));
+    }
+
+    Ok(Onsen::new(towels, designs))
 }
@@ -0,0 +1,6 @@
+use crate::day19::models::Onsen;
+use crate::generate::lines;
+
+pub fn serialize_input(onsen: &Onsen) -> String {
+    onsen.available_towels.join(", ") + "\n\n" + &lines(onsen.target_designs.iter().cloned())
+}
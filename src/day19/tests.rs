@@ -1,14 +1,24 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day19::Day19;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day19");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day19::default(), INPUT_EXAMPLE, 6);
+    test_part_one_common::<Day19>(INPUT_EXAMPLE, 6);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day19::default(), INPUT_EXAMPLE, 16);
+    test_part_two_common::<Day19>(INPUT_EXAMPLE, 16);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day19>("", 1, 1);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day19>(19);
 }
@@ -0,0 +1,37 @@
+use crate::generate::{lines, maze, Rng};
+use crate::grid::{Coordinates, Grid};
+use crate::pathfinding::bfs;
+
+/// Racetrack made of a single path winding through walls, the real input is 141 tiles wide.
+///
+/// The track is the path between the two farthest tiles of a random maze, every other corridor of
+/// the maze is filled with walls.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    // Smaller mazes have a single floor tile, the start and the end would be on the same tile
+    let side = size.unwrap_or(141).max(5);
+    let walls = maze(rng, side, side, 0.0);
+    let corridors = |node: &Coordinates| walls.neighbours(*node).filter(|next| !walls[*next]).collect::<Vec<_>>();
+
+    // The farthest tile from any tile is an end of the longest path of the maze
+    let farthest = |from: Coordinates| {
+        let search = bfs(from, corridors, |_| false);
+        // Ties are broken on the position so that the same seed always gives the same track
+        let (end, _) = search
+            .costs()
+            .iter()
+            .max_by_key(|(node, distance)| (**distance, **node))
+            .unwrap();
+        (*end, search.path_to(*end).unwrap_or_default())
+    };
+    let (start, _) = farthest(Coordinates::new(1, 1));
+    let (end, track) = farthest(start);
+
+    let mut map = Grid::new(walls.width(), walls.height(), '#');
+    for position in track {
+        map[position] = '.';
+    }
+    map[start] = 'S';
+    map[end] = 'E';
+
+    lines(map.rows().map(|row| row.iter().collect()))
+}
@@ -1,14 +1,37 @@
-use crate::day20::models::{Map, Tile};
 use std::cmp::min;
 
-#[allow(clippy::needless_range_loop)]
-pub fn find_shortcuts(map: &Map, scores: &[Vec<usize>], max_size: usize) -> usize {
+use crate::day20::models::{Map, Tile};
+use crate::grid::Grid;
+use crate::pathfinding::bfs;
+
+/// Compute the distance from the start of every tile of the track, `None` if the end cannot be reached
+pub fn compute_scores(map: &Map) -> Option<Grid<usize>> {
+    let search = bfs(
+        map.start,
+        |node| map.grid.neighbours(*node).filter(|next| map.grid[*next] == Tile::Floor),
+        |node| *node == map.end,
+    );
+    search.goal?;
+
+    // We put scores on a grid since they are looked up a lot when searching for shortcuts
+    let mut scores = Grid::new(map.grid.width(), map.grid.height(), usize::MAX);
+    for (position, score) in search.costs() {
+        scores[*position] = *score;
+    }
+
+    Some(scores)
+}
+
+pub fn find_shortcuts(map: &Map, scores: &Grid<usize>, max_size: usize) -> usize {
+    let width = map.grid.width();
+    let height = map.grid.height();
+
     // Store found shortcuts
     let mut result = 0;
 
     // For each point in the grind, find it's shortcuts
-    for y in 0..map.height {
-        for x in 0..map.width {
+    for y in 0..height {
+        for x in 0..width {
             // Only considers floor tiles
             if map.grid[y][x] == Tile::Wall {
                 continue;
@@ -19,8 +42,8 @@ pub fn find_shortcuts(map: &Map, scores: &[Vec<usize>], max_size: usize) -> usiz
             // For every reachable point that has a max distance of max_size
             let y_min = y.saturating_sub(max_size);
             let x_min = x.saturating_sub(max_size);
-            for y_end in y_min..min(y + max_size + 1, map.height) {
-                for x_end in x_min..min(x + max_size + 1, map.width) {
+            for y_end in y_min..min(y + max_size + 1, height) {
+                for x_end in x_min..min(x + max_size + 1, width) {
                     let distance = y_end.abs_diff(y) + x_end.abs_diff(x);
                     let end_score = scores[y_end][x_end];
                     if distance <= max_size
@@ -1,37 +1,77 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
+mod visualization;
 
-mod astar;
 #[cfg(test)]
 mod tests;
 
-use crate::day20::astar::astar;
-use crate::day20::logic::{find_shortcuts};
-use crate::day20::models::Map;
+use crate::day20::generator::generate;
+use crate::day20::logic::{compute_scores, find_shortcuts};
+pub use crate::day20::models::Map;
 use crate::day20::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day20::serializer::serialize_input;
+use crate::day20::visualization::frames;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day20 {
-    parsed_data: Option<Map>,
-    scores: Option<Vec<Vec<usize>>>,
-}
+pub struct Day20;
 
 impl AdventSolution for Day20 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Map;
+    type Prepared = (Map, Option<Grid<usize>>);
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(20))
+    }
+
+    fn serialize(map: &Self::Parsed) -> String {
+        serialize(map)
+    }
+
+    fn prepare(map: Self::Parsed) -> Self::Prepared {
+        let scores = compute_scores(&map);
+        (map, scores)
+    }
+
+    // There is no answer if the end of the racetrack cannot be reached
+    fn solve_part_one((map, scores): &Self::Prepared) -> Answer {
+        scores.as_ref().map(|scores| find_shortcuts(map, scores, 2)).into()
     }
 
-    fn prepare(&mut self) {
-        self.scores = astar(self.parsed_data.as_ref().unwrap())
+    fn solve_part_two((map, scores): &Self::Prepared) -> Answer {
+        scores.as_ref().map(|scores| find_shortcuts(map, scores, 20)).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        find_shortcuts(self.parsed_data.as_ref().unwrap(), self.scores.as_ref().unwrap(), 2) as i128
+    fn visualize(map: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(frames(map)))
     }
 
-    fn solve_part_two(&self) -> i128 {
-        find_shortcuts(self.parsed_data.as_ref().unwrap(), self.scores.as_ref().unwrap(), 20) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the racetrack
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Day20::parse(input.to_string())
+}
+
+/// Write the racetrack in the input format
+pub fn serialize(map: &Map) -> String {
+    serialize_input(map)
+}
+
+/// Number of cheats of up to 2 picoseconds that save enough time, `None` if the end cannot be reached
+pub fn part_one(map: &Map) -> Option<usize> {
+    Some(find_shortcuts(map, &compute_scores(map)?, 2))
+}
+
+/// Number of cheats of up to 20 picoseconds that save enough time, `None` if the end cannot be reached
+pub fn part_two(map: &Map) -> Option<usize> {
+    Some(find_shortcuts(map, &compute_scores(map)?, 20))
+}
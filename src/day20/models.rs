@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::grid::{Coordinates, Grid};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Tile {
     Floor,
@@ -17,26 +19,20 @@ impl Display for Tile {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Map {
-    pub grid: Vec<Vec<Tile>>,
-    pub start: (usize, usize),
-    pub end: (usize, usize),
-    pub width: usize,
-    pub height: usize,
+    pub grid: Grid<Tile>,
+    pub start: Coordinates,
+    pub end: Coordinates,
     pub save_target: usize,
 }
 
 impl Map {
-    pub fn new(grid: Vec<Vec<Tile>>, start: (usize, usize), end: (usize, usize)) -> Self {
-        let height = grid.len();
-        let width = grid[0].len();
-        let save_target = if height < 100 { 10 } else { 100 };
+    pub fn new(grid: Grid<Tile>, start: Coordinates, end: Coordinates) -> Self {
+        let save_target = if grid.height() < 100 { 10 } else { 100 };
 
         Self {
             grid,
             start,
             end,
-            width,
-            height,
             save_target,
         }
     }
@@ -44,17 +40,17 @@ impl Map {
 
 impl Display for Map {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (y, line) in self.grid.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                if self.start.0 == x && self.start.1 == y {
-                    write!(f, "S")?;
-                } else if self.end.0 == x && self.end.1 == y {
-                    write!(f, "E")?;
-                } else {
-                    write!(f, "{tile}")?;
-                }
+        for (position, tile) in self.grid.iter() {
+            if position == self.start {
+                write!(f, "S")?;
+            } else if position == self.end {
+                write!(f, "E")?;
+            } else {
+                write!(f, "{tile}")?;
+            }
+            if position.x + 1 == self.grid.width() {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
         Ok(())
     }
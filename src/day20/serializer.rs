@@ -0,0 +1,6 @@
+use crate::day20::models::Map;
+
+pub fn serialize_input(map: &Map) -> String {
+    // The map is displayed in the input format, the cheat target is deduced from its size
+    map.to_string()
+}
@@ -1,14 +1,19 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day20::Day20;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day20");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day20::default(), INPUT_EXAMPLE, 10);
+    test_part_one_common::<Day20>(INPUT_EXAMPLE, 10);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day20::default(), INPUT_EXAMPLE, 2268);
+    test_part_two_common::<Day20>(INPUT_EXAMPLE, 2268);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day20>(20);
 }
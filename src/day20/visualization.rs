@@ -0,0 +1,70 @@
+use colored::Colorize;
+
+use crate::day20::logic::compute_scores;
+use crate::day20::models::{Map, Tile};
+use crate::grid::{Coordinates, Grid};
+use crate::visualize::render_grid;
+
+/// Maximum length of a cheat in the first part of the puzzle
+const CHEAT_SIZE: usize = 2;
+
+fn render_cheat(map: &Map, cheat_start: Coordinates, cheat_end: Coordinates, header: &str) -> String {
+    let frame = render_grid(&map.grid, |position, tile| {
+        if position == cheat_start {
+            "1".green().bold()
+        } else if position == cheat_end {
+            "2".green().bold()
+        } else if position == map.start {
+            "S".magenta()
+        } else if position == map.end {
+            "E".magenta()
+        } else {
+            match tile {
+                Tile::Floor => ".".dimmed(),
+                Tile::Wall => "#".bold(),
+            }
+        }
+    });
+    format!("{header}\n{frame}")
+}
+
+/// Every cheat of the first part that saves at least the target time, with the time saved
+fn find_cheats(map: &Map, scores: &Grid<usize>) -> Vec<(Coordinates, Coordinates, usize)> {
+    let size = CHEAT_SIZE as isize;
+    let mut cheats = vec![];
+    for cheat_start in map.grid.find_all(&Tile::Floor) {
+        for dy in -size..=size {
+            for dx in -size..=size {
+                let Some(cheat_end) = cheat_start.offset(dx, dy) else {
+                    continue;
+                };
+                let distance = cheat_start.manhattan_distance(&cheat_end);
+                if distance > CHEAT_SIZE || map.grid.get(cheat_end) != Some(&Tile::Floor) {
+                    continue;
+                }
+
+                // Tiles that are not on the track keep an infinite score
+                if let Some(saved) = scores[cheat_end].checked_sub(scores[cheat_start] + distance) {
+                    if saved >= map.save_target {
+                        cheats.push((cheat_start, cheat_end, saved));
+                    }
+                }
+            }
+        }
+    }
+    cheats
+}
+
+/// Show every cheat of the first part that saves at least the target time, one frame per cheat
+pub fn frames(map: &Map) -> impl Iterator<Item = String> + '_ {
+    let cheats = compute_scores(map).map_or_else(Vec::new, |scores| find_cheats(map, &scores));
+
+    cheats.into_iter().map(|(cheat_start, cheat_end, saved)| {
+        render_cheat(
+            map,
+            cheat_start,
+            cheat_end,
+            &format!("cheat from {cheat_start} to {cheat_end} saves {saved} picoseconds"),
+        )
+    })
+}
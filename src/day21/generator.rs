@@ -0,0 +1,8 @@
+use crate::generate::{lines, Rng};
+
+/// Door codes of three digits followed by `A`, the real input has 5 codes
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(5);
+
+    lines((0..count).map(|_| format!("{:03}A", rng.range(0..1000))))
+}
@@ -178,6 +178,12 @@ fn compute_code_cost(
 ) -> i64 {
     let mut score = 0;
     for (start, end) in std::iter::once('A').chain(code.chars()).tuple_windows() {
+        if start == end {
+            // The robot is already on the key, it only has to press it again
+            score += double_robot_cost[A][A];
+            continue;
+        }
+
         let mut min_cost = i64::MAX;
         for simple_path in all_simple_paths::<Vec<_>, _>(&graph, nodes[&start], nodes[&end], 0, None) {
             // Build the associated sequence
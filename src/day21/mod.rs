@@ -1,29 +1,73 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod reference;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day21::generator::generate;
 use crate::day21::logic::{solve_part_one, solve_part_two};
 use crate::day21::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day21::reference::reference_part_one;
+use crate::day21::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day21 {
-    parsed_data: Option<Vec<String>>,
-}
+pub struct Day21;
 
 impl AdventSolution for Day21 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<String>;
+    type Prepared = Vec<String>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(21))
+    }
+
+    fn serialize(codes: &Self::Parsed) -> String {
+        serialize(codes)
+    }
+
+    fn prepare(codes: Self::Parsed) -> Self::Prepared {
+        codes
+    }
+
+    fn solve_part_one(codes: &Self::Prepared) -> Answer {
+        part_one(codes).into()
+    }
+
+    fn solve_part_two(codes: &Self::Prepared) -> Answer {
+        part_two(codes).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_one(codes: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_one(codes).into())
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the door codes
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Day21::parse(input.to_string())
+}
+
+/// Write the door codes in the input format
+pub fn serialize(codes: &[String]) -> String {
+    serialize_input(codes)
+}
+
+/// Sum of the complexities of the codes with two robots between the door and you
+pub fn part_one(codes: &[String]) -> i64 {
+    solve_part_one(codes)
+}
+
+/// Sum of the complexities of the codes with 25 robots between the door and you
+pub fn part_two(codes: &[String]) -> i64 {
+    solve_part_two(codes)
+}
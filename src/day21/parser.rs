@@ -1,3 +1,19 @@
-pub fn parse_input(input: String) -> Vec<String> {
-    input.lines().map(From::from).collect()
+use crate::errors::ParseError;
+
+pub fn parse_input(input: String) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            // Door codes are made of three digits followed by an A
+            let digits = line.chars().take_while(char::is_ascii_digit).count();
+            if digits != 3 {
+                Err(ParseError::at_line(y, digits, line, "expected a code of three digits"))
+            } else if &line[3..] != "A" {
+                Err(ParseError::at_line(y, 3, line, "expected a code ending with 'A'"))
+            } else {
+                Ok(line.to_string())
+            }
+        })
+        .collect()
 }
@@ -0,0 +1,96 @@
+use crate::pathfinding::bfs;
+
+/// Positions of the keys of the numeric keypad, the gap is at (0, 3)
+const NUMERIC_KEYPAD: [(char, (i64, i64)); 11] = [
+    ('7', (0, 0)),
+    ('8', (1, 0)),
+    ('9', (2, 0)),
+    ('4', (0, 1)),
+    ('5', (1, 1)),
+    ('6', (2, 1)),
+    ('1', (0, 2)),
+    ('2', (1, 2)),
+    ('3', (2, 2)),
+    ('0', (1, 3)),
+    ('A', (2, 3)),
+];
+
+/// Positions of the keys of a directional keypad, the gap is at (0, 0)
+const DIRECTIONAL_KEYPAD: [(char, (i64, i64)); 5] = [
+    ('^', (1, 0)),
+    ('A', (2, 0)),
+    ('<', (0, 1)),
+    ('v', (1, 1)),
+    ('>', (2, 1)),
+];
+
+fn key_at(keypad: &[(char, (i64, i64))], position: (i64, i64)) -> Option<char> {
+    keypad.iter().find(|(_, key)| *key == position).map(|(key, _)| *key)
+}
+
+/// Robots between the human and the door, the last one uses the numeric keypad
+const ROBOTS: usize = 3;
+
+type Positions = [(i64, i64); ROBOTS];
+
+/// Press `key` on the keypad of the robot `robot`, robots are numbered from the one the human
+/// controls. Returns the new positions of the robots and the key typed on the door if any, `None`
+/// if a robot would aim at a gap.
+fn press(mut positions: Positions, robot: usize, key: char) -> Option<(Positions, Option<char>)> {
+    if robot == ROBOTS {
+        // The last robot types on the door
+        return Some((positions, Some(key)));
+    }
+
+    let keypad = if robot == ROBOTS - 1 {
+        &NUMERIC_KEYPAD[..]
+    } else {
+        &DIRECTIONAL_KEYPAD[..]
+    };
+    let (x, y) = positions[robot];
+    let moved = match key {
+        '^' => (x, y - 1),
+        'v' => (x, y + 1),
+        '<' => (x - 1, y),
+        '>' => (x + 1, y),
+        _ => return press(positions, robot + 1, key_at(keypad, (x, y))?),
+    };
+
+    key_at(keypad, moved)?;
+    positions[robot] = moved;
+    Some((positions, None))
+}
+
+/// Search the shortest sequence of human presses typing `code`, every robot starts on its `A` key
+fn shortest_sequence(code: &str) -> usize {
+    let code: Vec<_> = code.chars().collect();
+    let mut start = [(2, 0); ROBOTS];
+    start[ROBOTS - 1] = (2, 3);
+
+    // A state is the position of every robot and the number of typed characters
+    let search = bfs(
+        (start, 0),
+        |&(positions, typed)| {
+            let code = &code;
+            ['^', 'v', '<', '>', 'A']
+                .into_iter()
+                .filter_map(move |key| match press(positions, 0, key)? {
+                    (positions, None) => Some((positions, typed)),
+                    (positions, Some(door_key)) => {
+                        (code.get(typed) == Some(&door_key)).then_some((positions, typed + 1))
+                    }
+                })
+        },
+        |(_, typed)| *typed == code.len(),
+    );
+
+    search.goal_cost().unwrap_or(0)
+}
+
+/// Search the presses with a breadth first search over the positions of the three robots
+pub fn reference_part_one(codes: &[String]) -> usize {
+    codes
+        .iter()
+        .map(|code| shortest_sequence(code) * code[..3].parse::<usize>().unwrap())
+        .sum()
+}
@@ -0,0 +1,5 @@
+use crate::generate::lines;
+
+pub fn serialize_input(codes: &[String]) -> String {
+    lines(codes.iter().cloned())
+}
@@ -1,14 +1,26 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day21::Day21;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day21");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day21::default(), INPUT_EXAMPLE, 126384);
+    test_part_one_common::<Day21>(INPUT_EXAMPLE, 126384);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day21::default(), INPUT_EXAMPLE, 154115708116294);
+    test_part_two_common::<Day21>(INPUT_EXAMPLE, 154115708116294u64);
+}
+
+#[test]
+fn test_regression_repeated_key() {
+    // The robot presses 0 twice without moving in between
+    test_part_one_common::<Day21>("900A\n", 43200);
+    test_part_two_common::<Day21>("900A\n", 50997018393000u64);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day21>(21);
 }
@@ -0,0 +1,9 @@
+use crate::generate::{lines, Rng};
+
+/// Initial secret numbers of the buyers, the real input has about 2000 buyers
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(2000);
+
+    // Secrets are pruned to 24 bits
+    lines((0..count).map(|_| rng.range(1..1 << 24).to_string()))
+}
@@ -59,12 +59,12 @@ impl Iterator for Secret {
 pub fn solve_part_one(secrets: &[u64]) -> u64 {
     secrets
         .iter()
-        .map(|secret| Secret::new(*secret).skip(2000).next().unwrap())
+        .map(|secret| Secret::new(*secret).nth(2000).unwrap())
         .sum()
 }
 
 #[inline(always)]
-fn convert_changes_to_offset(a: u64, b: u64, c: u64, d: u64) -> usize {
+fn convert_changes_to_offset(a: i64, b: i64, c: i64, d: i64) -> usize {
     (6859 * a + 361 * b + 19 * c + d + 65160) as usize
 }
 
@@ -80,11 +80,14 @@ pub fn solve_part_two(secrets: &[u64]) -> u64 {
     let mut sequences_profit: Vec<(usize, u64)> = vec![(0, 0); 2 << 17];
 
     for (i, secret) in secrets.iter().enumerate() {
-        for (a, b, c, d, e) in Secret::new(*secret).take(2000).tuple_windows() {
-            let offset = convert_changes_to_offset(b % 10 - a % 10, c % 10 - b % 10, d % 10 - c % 10, e % 10 - d % 10);
+        // Only keep prices, the ones digit of every secret. The initial secret and the 2000 new ones
+        // give 2000 price changes.
+        let prices = Secret::new(*secret).take(2001).map(|secret| (secret % 10) as i64);
+        for (a, b, c, d, e) in prices.tuple_windows() {
+            let offset = convert_changes_to_offset(b - a, c - b, d - c, e - d);
             // If we didn't see the sequence already
             if sequences_profit[offset].0 < i + 1 {
-                sequences_profit[offset] = (i + 1, sequences_profit[offset].1 + (e % 10));
+                sequences_profit[offset] = (i + 1, sequences_profit[offset].1 + e as u64);
             }
         }
     }
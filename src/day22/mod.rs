@@ -1,29 +1,77 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod reference;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day22::generator::generate;
 use crate::day22::logic::{solve_part_one, solve_part_two};
 use crate::day22::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day22::reference::{reference_part_one, reference_part_two};
+use crate::day22::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day22 {
-    parsed_data: Option<Vec<u64>>,
-}
+pub struct Day22;
 
 impl AdventSolution for Day22 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = Vec<u64>;
+    type Prepared = Vec<u64>;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(22))
+    }
+
+    fn serialize(secrets: &Self::Parsed) -> String {
+        serialize(secrets)
+    }
+
+    fn prepare(secrets: Self::Parsed) -> Self::Prepared {
+        secrets
+    }
+
+    fn solve_part_one(secrets: &Self::Prepared) -> Answer {
+        part_one(secrets).into()
+    }
+
+    fn solve_part_two(secrets: &Self::Prepared) -> Answer {
+        part_two(secrets).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_one(secrets: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_one(secrets).into())
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn reference_part_two(secrets: &Self::Parsed) -> Option<Answer> {
+        Some(reference_part_two(secrets).into())
     }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the initial secret numbers
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    Day22::parse(input.to_string())
+}
+
+/// Write the initial secret numbers in the input format
+pub fn serialize(secrets: &[u64]) -> String {
+    serialize_input(secrets)
+}
+
+/// Sum of the 2000th secret number of every buyer
+pub fn part_one(secrets: &[u64]) -> u64 {
+    solve_part_one(secrets)
+}
+
+/// Most bananas that can be bought with a single sequence of changes
+pub fn part_two(secrets: &[u64]) -> u64 {
+    solve_part_two(secrets)
 }
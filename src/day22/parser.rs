@@ -1,3 +1,12 @@
-pub fn parse_input(input: String) -> Vec<u64> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+use crate::errors::ParseError;
+
+pub fn parse_input(input: String) -> Result<Vec<u64>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.parse()
+                .map_err(|_| ParseError::at_line(y, 0, line, "expected a secret number"))
+        })
+        .collect()
 }
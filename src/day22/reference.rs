@@ -0,0 +1,49 @@
+use hashbrown::HashMap;
+
+/// Number of new secrets generated by each buyer
+const SECRETS: usize = 2000;
+
+fn next_secret(secret: u64) -> u64 {
+    let secret = ((secret * 64) ^ secret) % 16_777_216;
+    let secret = ((secret / 32) ^ secret) % 16_777_216;
+    ((secret * 2048) ^ secret) % 16_777_216
+}
+
+/// Initial secret followed by the new secrets of a buyer
+fn secrets(initial: u64) -> Vec<u64> {
+    let mut secrets = vec![initial];
+    for _ in 0..SECRETS {
+        secrets.push(next_secret(secrets[secrets.len() - 1]));
+    }
+    secrets
+}
+
+pub fn reference_part_one(initial_secrets: &[u64]) -> u64 {
+    initial_secrets.iter().map(|initial| secrets(*initial)[SECRETS]).sum()
+}
+
+/// Sum the price of each sequence of changes over every buyer
+pub fn reference_part_two(initial_secrets: &[u64]) -> u64 {
+    let mut bananas: HashMap<[i64; 4], u64> = HashMap::new();
+    for initial in initial_secrets {
+        let prices: Vec<i64> = secrets(*initial).iter().map(|secret| (secret % 10) as i64).collect();
+
+        // The monkey sells as soon as it sees the sequence for the first time
+        let mut first_prices = HashMap::new();
+        for window in prices.windows(5) {
+            let changes = [
+                window[1] - window[0],
+                window[2] - window[1],
+                window[3] - window[2],
+                window[4] - window[3],
+            ];
+            first_prices.entry(changes).or_insert(window[4] as u64);
+        }
+
+        for (changes, price) in first_prices {
+            *bananas.entry(changes).or_default() += price;
+        }
+    }
+
+    bananas.into_values().max().unwrap_or(0)
+}
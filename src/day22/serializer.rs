@@ -0,0 +1,5 @@
+use crate::generate::lines;
+
+pub fn serialize_input(secrets: &[u64]) -> String {
+    lines(secrets.iter().map(|secret| secret.to_string()))
+}
@@ -1,4 +1,4 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day22::Day22;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day22");
@@ -6,10 +6,21 @@ static INPUT_EXAMPLE_2: &str = include_str!("../../input_examples/day22_2");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day22::default(), INPUT_EXAMPLE, 37327623);
+    test_part_one_common::<Day22>(INPUT_EXAMPLE, 37327623);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day22::default(), INPUT_EXAMPLE_2, 23);
+    test_part_two_common::<Day22>(INPUT_EXAMPLE_2, 23);
+}
+
+#[test]
+fn test_regression_last_price_change() {
+    // The best sequence only pays off on the last of the 2000 price changes of the first buyer
+    test_part_two_common::<Day22>("42\n159\n", 18);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day22>(22);
 }
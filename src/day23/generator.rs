@@ -0,0 +1,35 @@
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+use crate::generate::{lines, word, Rng, LOWERCASE};
+
+/// Number of connections of each computer in the real input
+const DEGREE: usize = 13;
+
+/// Connections of a LAN party, the real input has 520 computers connected to 13 others.
+///
+/// A group of 13 computers that are all connected together is hidden in the network.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    // Computer names are made of two letters
+    let count = size.unwrap_or(520).clamp(DEGREE, LOWERCASE.len() * LOWERCASE.len());
+
+    let mut computers = HashSet::new();
+    while computers.len() < count {
+        computers.insert(word(rng, LOWERCASE, 2));
+    }
+    // Sort the set first so that the same seed always gives the same network
+    let mut computers: Vec<_> = computers.into_iter().sorted().collect();
+    rng.shuffle(&mut computers);
+
+    let mut connections: Vec<_> = computers[..DEGREE].iter().tuple_combinations().collect();
+    let connection_count = (count * DEGREE / 2).min(count * (count - 1) / 2);
+    while connections.len() < connection_count {
+        let (left, right) = (rng.choose(&computers), rng.choose(&computers));
+        if left != right && !connections.contains(&(left, right)) && !connections.contains(&(right, left)) {
+            connections.push((left, right));
+        }
+    }
+    rng.shuffle(&mut connections);
+
+    lines(connections.into_iter().map(|(left, right)| format!("{left}-{right}")))
+}
@@ -1,7 +1,8 @@
 use crate::day23::models::ConnectionMap;
+use crate::logging::trace;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
-// use petgraph::dot::{Config::EdgeNoLabel, Dot};
+use petgraph::dot::{Config::EdgeNoLabel, Dot};
 use petgraph::prelude::NodeIndex;
 use petgraph::{Graph, Undirected};
 
@@ -25,7 +26,7 @@ pub fn prepare(connection_map: &ConnectionMap) -> Network {
     }
 
     // Display the graph
-    // println!("{:?}", Dot::with_config(&graph, &[EdgeNoLabel]));
+    trace!("{:?}", Dot::with_config(&graph, &[EdgeNoLabel]));
 
     graph
 }
@@ -37,10 +38,8 @@ fn bron_kerbosch_2(
     mut x: HashSet<NodeIndex>,
     max_clique: &mut HashSet<NodeIndex>,
 ) {
-    if p.is_empty() && x.is_empty() {
-        if r.len() > max_clique.len() {
-            let _ = std::mem::replace(max_clique, r.clone());
-        }
+    if p.is_empty() && x.is_empty() && r.len() > max_clique.len() {
+        let _ = std::mem::replace(max_clique, r.clone());
     }
     if p.is_empty() {
         return;
@@ -48,7 +47,7 @@ fn bron_kerbosch_2(
 
     // Get a pivot from p
     let pivot = *p.iter().next().unwrap();
-    let pivot_neighbors: HashSet<_> = graph.neighbors(pivot).into_iter().collect();
+    let pivot_neighbors: HashSet<_> = graph.neighbors(pivot).collect();
 
     for node in p.clone() {
         // Don't consider pivot neighbors for the loop
@@ -60,7 +59,7 @@ fn bron_kerbosch_2(
         let mut r = r.clone();
         r.insert(node);
         // Get the neighbor set of the node
-        let neighbors: HashSet<_> = graph.neighbors(node).into_iter().collect();
+        let neighbors: HashSet<_> = graph.neighbors(node).collect();
         bron_kerbosch_2(
             graph,
             r,
@@ -101,20 +100,18 @@ pub fn solve_part_one(graph: &Network) -> usize {
     found_sets.len()
 }
 
-pub fn solve_part_two(graph: &Network) -> u32 {
+pub fn solve_part_two(graph: &Network) -> String {
     let mut max_clique = HashSet::new();
     bron_kerbosch_2(
-        &graph,
+        graph,
         HashSet::new(),
-        graph.node_indices().into_iter().collect(),
+        graph.node_indices().collect(),
         HashSet::new(),
         &mut max_clique
     );
 
-    // get the weight of the max clique, sort it and display it
-    let mut max_clique_weight: Vec<_> = max_clique.iter().map(|node|graph.node_weight(*node).unwrap()).collect();
+    // get the weight of the max clique, sort it and join it to build the password
+    let mut max_clique_weight: Vec<_> = max_clique.iter().map(|node| graph.node_weight(*node).unwrap()).collect();
     max_clique_weight.sort();
-    println!("{}", max_clique_weight.iter().join(","));
-
-    0
+    max_clique_weight.iter().join(",")
 }
@@ -1,35 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day23::generator::generate;
 use crate::day23::logic::{prepare, solve_part_one, solve_part_two, Network};
-use crate::day23::models::ConnectionMap;
+pub use crate::day23::models::ConnectionMap;
 use crate::day23::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day23::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day23 {
-    parsed_data: Option<ConnectionMap>,
-    prepared_data: Option<Network>,
-}
+pub struct Day23;
 
 impl AdventSolution for Day23 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = ConnectionMap;
+    type Prepared = Network;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(23))
+    }
+
+    fn serialize(connection_map: &Self::Parsed) -> String {
+        serialize(connection_map)
     }
 
-    fn prepare(&mut self) {
-        self.prepared_data = Some(prepare(self.parsed_data.as_ref().unwrap()));
+    fn prepare(connection_map: Self::Parsed) -> Self::Prepared {
+        prepare(&connection_map)
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.prepared_data.as_ref().unwrap()) as i128
+    fn solve_part_one(network: &Self::Prepared) -> Answer {
+        solve_part_one(network).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.prepared_data.as_ref().unwrap()) as i128
+    fn solve_part_two(network: &Self::Prepared) -> Answer {
+        solve_part_two(network).into()
     }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parse the network connections
+pub fn parse(input: &str) -> Result<ConnectionMap, ParseError> {
+    Day23::parse(input.to_string())
+}
+
+/// Write the network connections in the input format
+pub fn serialize(connection_map: &ConnectionMap) -> String {
+    serialize_input(connection_map)
+}
+
+/// Number of sets of three connected computers with a name starting with `t`
+pub fn part_one(connection_map: &ConnectionMap) -> usize {
+    solve_part_one(&prepare(connection_map))
+}
+
+/// Password of the LAN party
+pub fn part_two(connection_map: &ConnectionMap) -> String {
+    solve_part_two(&prepare(connection_map))
 }
@@ -1,25 +1,23 @@
 use nom::bytes::complete::tag;
-use nom::character::complete::{alpha1, line_ending,};
-use nom::combinator::{map, opt};
-use nom::multi::{many1,};
-use nom::sequence::tuple;
+use nom::character::complete::alpha1;
+use nom::combinator::map;
+use nom::sequence::separated_pair;
 use nom::{IResult, Parser};
 
 use crate::day23::models::ConnectionMap;
+use crate::errors::ParseError;
+use crate::parsing::{finish, line, many1_complete};
 
 fn parse_connection(input: &str) -> IResult<&str, (String, String)> {
     map(
-        tuple((alpha1, tag("-"), alpha1, opt(line_ending))),
-        |(left, _, right, _): (&str, _, &str, _)| (left.to_string(), right.to_string()),
+        line(separated_pair(alpha1, tag("-"), alpha1)),
+        |(left, right): (&str, &str)| (left.to_string(), right.to_string()),
     )
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> ConnectionMap {
-    let (res, connections) = many1(parse_connection).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
+pub fn parse_input(input: String) -> Result<ConnectionMap, ParseError> {
+    let connections = finish(&input, many1_complete(parse_connection).parse(&input))?;
 
-    ConnectionMap { connections }
+    Ok(ConnectionMap { connections })
 }
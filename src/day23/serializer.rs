@@ -0,0 +1,11 @@
+use crate::day23::models::ConnectionMap;
+use crate::generate::lines;
+
+pub fn serialize_input(connection_map: &ConnectionMap) -> String {
+    lines(
+        connection_map
+            .connections
+            .iter()
+            .map(|(left, right)| format!("{left}-{right}")),
+    )
+}
@@ -0,0 +1,111 @@
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+
+use crate::generate::{lines, word, Rng, LOWERCASE};
+
+/// Ripple carry adder whose gates are being built
+struct Adder {
+    gates: Vec<(String, &'static str, String, String)>,
+    names: HashSet<String>,
+}
+
+impl Adder {
+    /// New wire name of three letters that cannot be mistaken for an input or an output
+    fn wire(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name = word(rng, &LOWERCASE[..23], 3);
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn gate(&mut self, rng: &mut Rng, left: &str, operation: &'static str, right: &str, result: &str) {
+        // Operands are written in any order
+        let (left, right) = if rng.chance(0.5) { (left, right) } else { (right, left) };
+        self.gates
+            .push((left.to_string(), operation, right.to_string(), result.to_string()));
+    }
+}
+
+/// Gates of an adder of two numbers whose outputs have been swapped four times, the real input
+/// adds numbers of 45 bits.
+///
+/// Swapped outputs are always in the same full adder, like in the real input, and full adders
+/// with a swap are never next to each other.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let bits = size.unwrap_or(45).clamp(12, 63);
+    let mut adder = Adder {
+        gates: vec![],
+        names: HashSet::new(),
+    };
+
+    // Half adder of the first bit, then a full adder for each bit
+    let mut carry = adder.wire(rng);
+    adder.gate(rng, "x00", "XOR", "y00", "z00");
+    adder.gate(rng, "x00", "AND", "y00", &carry);
+    let mut full_adders = vec![];
+    for bit in 1..bits {
+        let (x, y, z) = (format!("x{bit:02}"), format!("y{bit:02}"), format!("z{bit:02}"));
+        let [partial_sum, partial_carry, carried] = [adder.wire(rng), adder.wire(rng), adder.wire(rng)];
+        let next_carry = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            adder.wire(rng)
+        };
+
+        adder.gate(rng, &x, "XOR", &y, &partial_sum);
+        adder.gate(rng, &x, "AND", &y, &partial_carry);
+        adder.gate(rng, &partial_sum, "XOR", &carry, &z);
+        adder.gate(rng, &partial_sum, "AND", &carry, &carried);
+        adder.gate(rng, &partial_carry, "OR", &carried, &next_carry);
+        full_adders.push([z, partial_sum, partial_carry, carried, next_carry.clone()]);
+        carry = next_carry;
+    }
+
+    // Pick four full adders that are not next to each other, the last one is left untouched
+    let mut candidates: Vec<_> = (1..bits - 2).collect();
+    let mut swapped_bits = vec![];
+    while swapped_bits.len() < 4 {
+        rng.shuffle(&mut candidates);
+        swapped_bits = candidates[..4].to_vec();
+        swapped_bits.sort_unstable();
+        if swapped_bits
+            .iter()
+            .tuple_windows()
+            .any(|(left, right)| left + 1 >= *right)
+        {
+            swapped_bits.clear();
+        }
+    }
+
+    let mut swaps = HashMap::new();
+    for bit in swapped_bits {
+        let [z, partial_sum, partial_carry, carried, next_carry] = &full_adders[bit - 1];
+        let (left, right) = match rng.range(0..4) {
+            0 => (z, next_carry),
+            1 => (z, carried),
+            2 => (z, partial_carry),
+            _ => (partial_sum, partial_carry),
+        };
+        swaps.insert(left.clone(), right.clone());
+        swaps.insert(right.clone(), left.clone());
+    }
+    for gate in &mut adder.gates {
+        if let Some(swapped) = swaps.get(&gate.3) {
+            gate.3 = swapped.clone();
+        }
+    }
+    rng.shuffle(&mut adder.gates);
+
+    let wires = ["x", "y"]
+        .into_iter()
+        .flat_map(|input| (0..bits).map(move |bit| format!("{input}{bit:02}")))
+        .map(|wire| format!("{wire}: {}", rng.range(0..2)));
+    let gates = adder
+        .gates
+        .iter()
+        .map(|(left, operation, right, result)| format!("{left} {operation} {right} -> {result}"));
+
+    lines(wires.collect::<Vec<_>>()) + "\n" + &lines(gates)
+}
@@ -1,4 +1,5 @@
 use crate::day24::models::{FullGateError, Gate, Operation, System, Wire};
+use crate::logging::debug;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use once_cell::sync::Lazy;
@@ -53,7 +54,7 @@ fn reconstruct_output(wire_values: &HashMap<String, bool>) -> u64 {
     // Build the result value
     let mut result: u64 = 0;
     for (key, value) in wire_values {
-        if key.starts_with("z") && *value == true {
+        if key.starts_with("z") && *value {
             // Get the positioning of the value
             let position: u64 = key.as_str()[1..].parse().unwrap();
             result += 1 << position;
@@ -89,14 +90,15 @@ pub fn solve_part_one(system: &System) -> u64 {
     reconstruct_output(&wire_values)
 }
 
-pub fn solve_part_two(system: &System) -> u32 {
+/// Return the sorted list of swapped wires, or None if the system does not contain an adder
+pub fn solve_part_two(system: &System) -> Option<String> {
     // A binary adder always use three values to work (except for the lowest digit):
     // the left operand, the right operand and the carry from the previous bit operation.
 
     // In order to test our program, we just have to test every adder from the z00 adder to the
-    // z44 adder. Since every adder only depends on the carry of the last operation and not
-    // of the full operation, giving that we tested the adder for z_n, we can test the adder for
-    // z_(n+1) with only 8 inputs.
+    // last one, z44 in the real input. Since every adder only depends on the carry of the last
+    // operation and not of the full operation, giving that we tested the adder for z_n, we can
+    // test the adder for z_(n+1) with only 8 inputs.
 
     // Doing this will allow us to detect which wire contains the carry of this operation. This
     // will allow us to accelerate the simulation of the next target.
@@ -110,7 +112,14 @@ pub fn solve_part_two(system: &System) -> u32 {
     // 1. Create a mapping of current program
     let wire_destinations = get_wire_destinations_from_gates(&system.gates);
 
-    // 2. Detect the carry of the first operation
+    // 2. An adder has one more output than the number of bits of its inputs
+    let input_bits = system.wires.iter().filter(|wire| wire.name.starts_with('x')).count() as i32;
+    let output_bits = system.gates.iter().filter(|gate| gate.result.starts_with('z')).count() as i32;
+    if output_bits != input_bits + 1 {
+        return None;
+    }
+
+    // 3. Detect the carry of the first operation. If there is none, the system is not an adder
     let mut carry_wire = system
         .gates
         .iter()
@@ -119,22 +128,20 @@ pub fn solve_part_two(system: &System) -> u32 {
                 && gate.operation == Operation::And
         })
         .map(|gate| gate.result.clone())
-        .next()
-        .unwrap();
+        .next()?;
 
-    // 3. Check the next full adder
+    // 4. Check the next full adder, up to the one before the last: its carry is the last output
     let mut swapped_wires = vec![];
-    for to_test in 1..44 {
+    for to_test in 1..output_bits - 2 {
         // Check the gate to detect if the full adder for bit to_test is right
         let is_valid = check_full_adder(&wire_destinations, &carry_wire, to_test);
 
         match is_valid {
             Ok(new_carry_wirer) => carry_wire = new_carry_wirer,
             Err((impacted_gates, errors)) => {
-                // println!("===We found an error at the full adder {to_test}===");
-                // println!("{:#?}", &swapped_wires);
-                // println!("last carry: {carry_wire}");
-                // println!("{to_test}:{:#?}:{:#?}", impacted_gates, errors);
+                debug!("found an error at the full adder {to_test}, last carry: {carry_wire}");
+                debug!("swapped wires so far: {:?}", &swapped_wires);
+                debug!("{to_test}: {:?}: {:?}", impacted_gates, errors);
 
                 // Simple case, we have the right types of gates, the output is reached but is wrong
                 // Count the types of each gates
@@ -157,16 +164,15 @@ pub fn solve_part_two(system: &System) -> u32 {
                 {
                     carry_wire = fix_case_one(to_test, &carry_wire, &impacted_gates, &mut swapped_wires);
                 } else {
-                    unimplemented!()
+                    // The system is not an adder with a known kind of swap
+                    return None;
                 }
             }
         }
     }
 
     swapped_wires.sort();
-    println!("{}", swapped_wires.iter().join(","));
-
-    0
+    Some(swapped_wires.iter().join(","))
 }
 
 fn fix_case_one(
@@ -199,7 +205,7 @@ fn fix_case_one(
                     break;
                 }
             }
-            if is_correct == true {
+            if is_correct {
                 correct_carry = Some(wire.clone());
                 break;
             }
@@ -279,7 +285,7 @@ fn fix_case_one(
     let and_gates: Vec<Gate> = impacted_gates
         .iter()
         .filter(|gate| gate.operation == Operation::And)
-        .map(|gate| gate.clone())
+        .cloned()
         .collect();
 
     // We now that there are two end gates from the test at the beginning
@@ -343,7 +349,7 @@ fn fix_case_one(
 }
 
 /// Check if the full adder for bit "to_test" is valid
-fn check_full_adder<'a>(
+fn check_full_adder(
     wire_destinations: &HashMap<String, Vec<&Gate>>,
     carry_wire: &String,
     to_test: i32,
@@ -374,10 +380,10 @@ fn check_full_adder<'a>(
                     .collect();
 
                 // Create initial signals
-                let signals: VecDeque<_> = [&x, &y, &carry_wire].into_iter().collect();
+                let signals: VecDeque<_> = [&x, &y, carry_wire].into_iter().collect();
 
                 // Run it
-                simulate_gates(&wire_destinations, &mut wire_values, signals);
+                simulate_gates(wire_destinations, &mut wire_values, signals);
 
                 // Append the result to the list of results
                 for (key, value) in wire_values {
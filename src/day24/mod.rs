@@ -1,30 +1,68 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::day24::generator::generate;
 use crate::day24::logic::{solve_part_one, solve_part_two};
-use crate::day24::models::System;
+pub use crate::day24::models::System;
 use crate::day24::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day24::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day24 {
-    parsed_data: Option<System>,
-}
+pub struct Day24;
 
 impl AdventSolution for Day24 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = System;
+    type Prepared = System;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(24))
+    }
+
+    fn serialize(system: &Self::Parsed) -> String {
+        serialize(system)
+    }
+
+    fn prepare(system: Self::Parsed) -> Self::Prepared {
+        system
+    }
+
+    fn solve_part_one(system: &Self::Prepared) -> Answer {
+        part_one(system).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(system: &Self::Prepared) -> Answer {
+        part_two(system).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the initial wires and the gates
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    Day24::parse(input.to_string())
+}
+
+/// Write the initial wires and the gates in the input format
+pub fn serialize(system: &System) -> String {
+    serialize_input(system)
+}
+
+/// Number output on the `z` wires
+pub fn part_one(system: &System) -> u64 {
+    solve_part_one(system)
+}
+
+/// Names of the swapped wires, sorted and joined with commas
+pub fn part_two(system: &System) -> Option<String> {
+    solve_part_two(system)
+}
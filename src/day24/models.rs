@@ -7,13 +7,15 @@ pub enum Operation {
     Or,
 }
 
-impl From<&str> for Operation {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
-            "AND" => Self::And,
-            "XOR" => Self::Xor,
-            "OR" => Self::Or,
-            _ => unreachable!(),
+            "AND" => Ok(Self::And),
+            "XOR" => Ok(Self::Xor),
+            "OR" => Ok(Self::Or),
+            _ => Err(format!("unknown operation '{value}'")),
         }
     }
 }
@@ -60,7 +62,7 @@ pub struct Wire {
 
 impl Display for Wire {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", self.name, self.value)
+        write!(f, "{}: {}", self.name, u8::from(self.value))
     }
 }
 
@@ -1,18 +1,20 @@
-use crate::day24::models::{Gate, Operation, System, Wire};
-use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{alphanumeric1, digit1, line_ending};
-use nom::combinator::{map, opt};
+use nom::character::complete::{alpha1, alphanumeric1, one_of};
+use nom::combinator::{map, map_res};
 use nom::multi::many1;
-use nom::sequence::tuple;
+use nom::sequence::{separated_pair, tuple};
 use nom::{IResult, Parser};
 
+use crate::day24::models::{Gate, Operation, System, Wire};
+use crate::errors::ParseError;
+use crate::parsing::{blank_lines, finish, line, many1_complete};
+
 fn parse_wire(input: &str) -> IResult<&str, Wire> {
     map(
-        tuple((alphanumeric1, tag(": "), digit1, opt(line_ending))),
-        |(name, _, value, _): (&str, _, &str, _)| Wire {
+        line(separated_pair(alphanumeric1, tag(": "), one_of("01"))),
+        |(name, value): (&str, char)| Wire {
             name: name.to_string(),
-            value: if value == "1" { true } else { false },
+            value: value == '1',
         },
     )
     .parse(input)
@@ -20,19 +22,18 @@ fn parse_wire(input: &str) -> IResult<&str, Wire> {
 
 fn parse_gate(input: &str) -> IResult<&str, Gate> {
     map(
-        tuple((
+        line(tuple((
             alphanumeric1,
             tag(" "),
-            alt((tag("AND"), tag("XOR"), tag("OR"))),
+            map_res(alpha1, Operation::try_from),
             tag(" "),
             alphanumeric1,
             tag(" -> "),
             alphanumeric1,
-            opt(line_ending),
-        )),
-        |(left, _, operation, _, right, _, result, _): (&str, _, &str, _, &str, _, &str, _)| Gate {
+        ))),
+        |(left, _, operation, _, right, _, result): (&str, _, _, _, &str, _, &str)| Gate {
             left: left.to_string(),
-            operation: Operation::from(operation),
+            operation,
             right: right.to_string(),
             result: result.to_string(),
         },
@@ -42,17 +43,12 @@ fn parse_gate(input: &str) -> IResult<&str, Gate> {
 
 fn parse_system(input: &str) -> IResult<&str, System> {
     map(
-        tuple((many1(parse_wire), many1(line_ending), many1(parse_gate))),
-        |(wires, _, gates)| System { wires, gates },
+        separated_pair(many1(parse_wire), blank_lines, many1_complete(parse_gate)),
+        |(wires, gates)| System { wires, gates },
     )
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> System {
-    let (res, system) = parse_system.parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    system
+pub fn parse_input(input: String) -> Result<System, ParseError> {
+    finish(&input, parse_system.parse(&input))
 }
@@ -0,0 +1,6 @@
+use crate::day24::models::System;
+
+pub fn serialize_input(system: &System) -> String {
+    // The system is displayed in the input format
+    system.to_string()
+}
@@ -1,14 +1,41 @@
-use crate::common::{test_part_one_common, test_part_two_common};
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
 use crate::day24::Day24;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day24");
+static INPUT_EXAMPLE_SMALL: &str = include_str!("../../input_examples/day24_small");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(Day24::default(), INPUT_EXAMPLE, 2024);
+    test_part_one_common::<Day24>(INPUT_EXAMPLE, 2024);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(Day24::default(), INPUT_EXAMPLE, 0);
+    test_part_two_common::<Day24>(INPUT_EXAMPLE, Answer::NotApplicable);
+    test_part_two_common::<Day24>(INPUT_EXAMPLE_SMALL, Answer::NotApplicable);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day24>("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n", 4, 5);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day24>(24);
+}
+
+#[test]
+fn test_part_two_on_smaller_adders() {
+    // The number of full adders to check comes from the outputs, not from the size of the real input
+    for bits in [12, 16, 20, 30] {
+        let input = Day24::generate(&mut Rng::new(bits), Some(bits as usize)).unwrap();
+        let system = Day24::prepare(Day24::parse(input).unwrap());
+        let Answer::Text(wires) = Day24::solve_part_two(&system) else {
+            panic!("no swapped wires found in an adder of {bits} bits");
+        };
+        assert_eq!(wires.split(',').count(), 8, "{wires}");
+    }
 }
@@ -0,0 +1,33 @@
+use crate::generate::Rng;
+
+/// Schematics of locks and keys, the real input has 250 locks and 250 keys
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let count = size.unwrap_or(500);
+
+    let schematics: Vec<_> = (0..count)
+        .map(|_| {
+            // Heights of the 5 pins, locks are filled from the top and keys from the bottom
+            let is_lock = rng.chance(0.5);
+            let heights: Vec<_> = (0..5).map(|_| rng.range(0..6)).collect();
+
+            (0..7)
+                .map(|y| {
+                    let line: String = heights
+                        .iter()
+                        .map(|height| {
+                            let filled = if is_lock { y <= *height } else { 6 - y <= *height };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect();
+                    line + "\n"
+                })
+                .collect::<String>()
+        })
+        .collect();
+
+    schematics.join("\n")
+}
@@ -1,29 +1,63 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
-use crate::day25::logic::{solve_part_one, solve_part_two};
+use crate::day25::generator::generate;
+use crate::day25::logic::solve_part_one;
 use crate::day25::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::day25::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct Day25 {
-    parsed_data: Option<(Vec<u64>, Vec<u64>)>,
-}
+pub struct Day25;
 
 impl AdventSolution for Day25 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = (Vec<u64>, Vec<u64>);
+    type Prepared = (Vec<u64>, Vec<u64>);
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(25))
+    }
+
+    fn serialize(schematics: &Self::Parsed) -> String {
+        serialize(schematics)
+    }
+
+    fn prepare(schematics: Self::Parsed) -> Self::Prepared {
+        schematics
+    }
+
+    fn solve_part_one(schematics: &Self::Prepared) -> Answer {
+        part_one(schematics).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(_schematics: &Self::Prepared) -> Answer {
+        // There is no puzzle for the second part of the last day
+        Answer::NotApplicable
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the locks and the keys
+pub fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    Day25::parse(input.to_string())
+}
+
+/// Write the locks and the keys in the input format
+pub fn serialize(schematics: &(Vec<u64>, Vec<u64>)) -> String {
+    serialize_input(schematics)
+}
+
+/// Number of lock and key pairs that fit together
+pub fn part_one(schematics: &(Vec<u64>, Vec<u64>)) -> usize {
+    solve_part_one(schematics)
+}
@@ -1,18 +1,55 @@
-use itertools::Itertools;
+use crate::errors::ParseError;
+use crate::grid::Grid;
+use crate::parsing::sections;
 
-pub fn parse_input(input: String) -> (Vec<u64>, Vec<u64>) {
-    input
-        .lines()
-        .chunk_by(|line| !line.is_empty())
-        .into_iter()
-        .filter_map(|(to_use, chunks)| if to_use == true { Some(chunks) } else { None })
-        .map(|chunks| {
-            chunks.into_iter().fold(0, |acc, line| {
-                (acc << 8)
-                    + line
-                        .chars()
-                        .fold(0, |acc, char| (acc << 1) + if char == '#' { 1 } else { 0 })
-            })
+pub fn parse_input(input: String) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut schematics = vec![];
+    for (first_line, section) in sections(&input) {
+        let schematic = Grid::parse(section, |_, c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '#' or '.'".to_string()),
         })
-        .partition(|value| value & 1 == 1)
+        .map_err(|error| error.offset_lines(first_line))?;
+
+        // Locks and keys are blocks of 7 lines of 5 pins
+        let first = section.lines().next().unwrap_or_default();
+        if schematic.width() != 5 {
+            return Err(ParseError::at_line(
+                first_line,
+                schematic.width().min(5),
+                first,
+                "expected a line of 5 characters",
+            ));
+        }
+        if schematic.height() != 7 {
+            let last = section.lines().last().unwrap_or_default();
+            return Err(ParseError::at_line(
+                first_line + schematic.height() - 1,
+                0,
+                last,
+                "expected a schematic of 7 lines",
+            ));
+        }
+
+        // Locks have a full first line and an empty last line, keys are the opposite
+        let is_full = |y: usize| schematic[y].iter().all(|pin| *pin);
+        let is_empty = |y: usize| schematic[y].iter().all(|pin| !*pin);
+        let is_lock = is_full(0) && is_empty(6);
+        let is_key = is_empty(0) && is_full(6);
+        if !is_lock && !is_key {
+            return Err(ParseError::at_line(
+                first_line,
+                0,
+                first,
+                "expected a lock or a key, the first and last lines must be one full and one empty",
+            ));
+        }
+
+        schematics.push(schematic.rows().fold(0, |acc, line| {
+            (acc << 8) + line.iter().fold(0, |acc, pin| (acc << 1) + u64::from(*pin))
+        }));
+    }
+
+    Ok(schematics.into_iter().partition(|value| value & 1 == 1))
 }
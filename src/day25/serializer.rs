@@ -0,0 +1,23 @@
+use itertools::Itertools;
+
+/// Schematics are 7 lines of 5 pins
+const HEIGHT: usize = 7;
+const WIDTH: usize = 5;
+
+/// Draw a schematic, each line is a byte where the most significant one is the first line
+fn draw(schematic: u64) -> String {
+    (0..HEIGHT)
+        .rev()
+        .map(|y| {
+            (0..WIDTH)
+                .rev()
+                .map(|x| if (schematic >> (8 * y + x)) & 1 == 1 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+pub fn serialize_input((keys, locks): &(Vec<u64>, Vec<u64>)) -> String {
+    // Locks and keys are not interleaved like in the real input, this does not change the model
+    locks.iter().chain(keys).map(|schematic| draw(*schematic)).join("\n\n") + "\n"
+}
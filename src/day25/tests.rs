@@ -1,14 +1,25 @@
-use crate::common::{test_part_one_common, test_part_two_common};
-use crate::dayxx::DayXX;
+use crate::common::{test_parse_error_common, test_part_one_common, test_part_two_common, test_round_trip_common};
+use crate::day25::Day25;
+use crate::models::Answer;
 
 static INPUT_EXAMPLE: &str = include_str!("../../input_examples/day25");
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(DayXX::default(), INPUT_EXAMPLE, 3);
+    test_part_one_common::<Day25>(INPUT_EXAMPLE, 3);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(DayXX::default(), INPUT_EXAMPLE, 0);
+    test_part_two_common::<Day25>(INPUT_EXAMPLE, Answer::NotApplicable);
+}
+
+#[test]
+fn test_parse_error() {
+    test_parse_error_common::<Day25>("#####\n.####\n.####\n.####\n.#.##\n...##\n#####\n", 1, 1);
+}
+
+#[test]
+fn test_round_trip() {
+    test_round_trip_common::<Day25>(25);
 }
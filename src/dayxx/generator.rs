@@ -0,0 +1,6 @@
+use crate::generate::{lines, word, Rng, LOWERCASE};
+
+/// Lines of random words, to replace with inputs shaped like the puzzle ones
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    lines((0..size.unwrap_or(10)).map(|_| word(rng, LOWERCASE, 8)))
+}
@@ -1,29 +1,67 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
+mod serializer;
 
 #[cfg(test)]
 mod tests;
 
+use crate::dayxx::generator::generate;
 use crate::dayxx::logic::{solve_part_one, solve_part_two};
 use crate::dayxx::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::dayxx::serializer::serialize_input;
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::models::{AdventSolution, Answer};
 
-#[derive(Default)]
-pub struct DayXX {
-    parsed_data: Option<String>,
-}
+pub struct DayXX;
 
 impl AdventSolution for DayXX {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    type Parsed = String;
+    type Prepared = String;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError> {
+        parse_input(data).map_err(|error| error.with_day(0))
+    }
+
+    fn serialize(data: &Self::Parsed) -> String {
+        serialize(data)
+    }
+
+    fn prepare(data: Self::Parsed) -> Self::Prepared {
+        data
+    }
+
+    fn solve_part_one(data: &Self::Prepared) -> Answer {
+        part_one(data).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(data: &Self::Prepared) -> Answer {
+        part_two(data).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate(rng, size))
     }
 }
+
+/// Parse the puzzle input
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    DayXX::parse(input.to_string())
+}
+
+/// Write the parsed data in the input format
+pub fn serialize(data: &str) -> String {
+    serialize_input(data)
+}
+
+/// Answer of the first part
+pub fn part_one(data: &str) -> u32 {
+    solve_part_one(data)
+}
+
+/// Answer of the second part
+pub fn part_two(data: &str) -> u32 {
+    solve_part_two(data)
+}
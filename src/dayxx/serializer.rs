@@ -0,0 +1,3 @@
+pub fn serialize_input(data: &str) -> String {
+    data.to_string()
+}
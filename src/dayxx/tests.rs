@@ -5,10 +5,10 @@ static INPUT_EXAMPLE: &str = "";
 
 #[test]
 fn test_part_one() {
-    test_part_one_common(DayXX::default(), INPUT_EXAMPLE, 0);
+    test_part_one_common::<DayXX>(INPUT_EXAMPLE, 0);
 }
 
 #[test]
 fn test_part_two() {
-    test_part_two_common(DayXX::default(), INPUT_EXAMPLE, 0);
+    test_part_two_common::<DayXX>(INPUT_EXAMPLE, 0);
 }
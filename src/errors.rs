@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use nom::error::ErrorKind;
+
+/// Error returned when a puzzle input cannot be parsed.
+///
+/// Lines and columns start at 1 and columns are counted in characters.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    /// Day of the parser that failed, set by the solver
+    pub day: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    /// Full text of the offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create an error from zero-based line and column indexes, as given by `enumerate`
+    pub fn at_line(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: line + 1,
+            column: column + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Create an error located at the start of `position`, which must be a sub slice of `input`.
+    /// This is what nom returns as the remaining input when a parser fails.
+    pub fn at(input: &str, position: &str, message: impl Into<String>) -> Self {
+        let offset = (position.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+
+        // Find the line containing the offset
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |position| position + 1);
+        let column = before[line_start..].chars().count();
+        let text = input[line_start..].lines().next().unwrap_or_default();
+
+        Self::at_line(line, column, text, message)
+    }
+
+    /// Create an error from a failed nom parser
+    pub fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => Self::at(input, &input[input.len()..], "unexpected end of input"),
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                Self::at(input, error.input, describe_error_kind(error.code))
+            }
+        }
+    }
+
+    /// Move the error down by `lines`, for errors found in a section that does not start the input
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn with_day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+/// Give a human-readable explanation of the nom combinator that failed
+fn describe_error_kind(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Tag => "unexpected text".to_string(),
+        ErrorKind::Digit => "expected a number".to_string(),
+        ErrorKind::Alpha => "expected a letter".to_string(),
+        ErrorKind::AlphaNumeric => "expected a letter or a digit".to_string(),
+        ErrorKind::Space => "expected a space".to_string(),
+        ErrorKind::CrLf => "expected a line ending".to_string(),
+        ErrorKind::OneOf => "unexpected character".to_string(),
+        ErrorKind::MapRes => "invalid value".to_string(),
+        ErrorKind::Verify => "value out of range".to_string(),
+        ErrorKind::Many1 | ErrorKind::SeparatedList => "expected at least one item".to_string(),
+        ErrorKind::Eof => "unexpected trailing input".to_string(),
+        kind => format!("unexpected input ({})", kind.description()),
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day:0>2}, ")?;
+        }
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+
+        // Display the offending line with a marker under the faulty character
+        let gutter = self.line.to_string();
+        writeln!(f, "{} |", " ".repeat(gutter.len()))?;
+        writeln!(f, "{gutter} | {}", self.text)?;
+        write!(f, "{} | {}^", " ".repeat(gutter.len()), " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error returned when a day cannot be solved from the library
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolveError {
+    /// No solver is registered for this day
+    UnknownDay(usize),
+    InvalidInput(ParseError),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::InvalidInput(error)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownDay(day) => write!(f, "no solver for day {day}"),
+            SolveError::InvalidInput(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::UnknownDay(_) => None,
+            SolveError::InvalidInput(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Property-based testing of the parsers.
+//!
+//! Inputs are mutated versions of generated inputs, or random text made of the characters that
+//! inputs usually contain. When a property fails, the input is shrunk to a minimal failing one.
+
+#[cfg(test)]
+mod tests;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate::Rng;
+
+/// Characters that often break parsers: separators, signs, digits and line endings
+const INTERESTING: &[char] = &[
+    '0', '1', '9', '-', '+', ',', ':', '|', '=', '#', '.', ' ', '\n', '\r', 'x', 'A',
+];
+
+/// Numbers too big for every integer type used by the parsers
+const HUGE_NUMBER: &str = "340282366920938463463374607431768211456";
+
+/// Input for which a property does not hold
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Failure {
+    /// Input that was generated
+    pub input: String,
+    /// Smallest input, found by shrinking `input`, for which the property does not hold
+    pub shrunk: String,
+    pub message: String,
+}
+
+/// Random character of the input or of the interesting characters
+fn random_char(rng: &mut Rng, chars: &[char]) -> char {
+    if chars.is_empty() || rng.chance(0.5) {
+        *rng.choose(INTERESTING)
+    } else {
+        *rng.choose(chars)
+    }
+}
+
+/// Apply a random mutation to an input: characters or lines are removed, inserted, replaced or
+/// swapped, the input is truncated or receives a huge number
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut lines: Vec<String> = input.lines().map(str::to_string).collect();
+    // Positions between two characters, and of an existing character
+    let position = rng.range(0..chars.len() + 1);
+    let index = position.min(chars.len().saturating_sub(1));
+
+    match rng.below(8) {
+        0 if !chars.is_empty() => {
+            chars.remove(index);
+        }
+        1 => {
+            let c = random_char(rng, &chars);
+            chars.insert(position, c);
+        }
+        2 if !chars.is_empty() => {
+            let c = random_char(rng, &chars);
+            chars[index] = c;
+        }
+        3 => chars.truncate(position),
+        4 => {
+            let end = chars.split_off(position);
+            chars.extend(HUGE_NUMBER.chars().chain(end));
+        }
+        5 if !lines.is_empty() => {
+            let line = rng.range(0..lines.len());
+            lines.remove(line);
+            return lines.join("\n");
+        }
+        6 if !lines.is_empty() => {
+            let line = rng.range(0..lines.len());
+            let duplicate = lines[line].clone();
+            lines.insert(rng.range(0..lines.len() + 1), duplicate);
+            return lines.join("\n");
+        }
+        7 if lines.len() > 1 => {
+            let (first, second) = (rng.range(0..lines.len()), rng.range(0..lines.len()));
+            lines.swap(first, second);
+            return lines.join("\n");
+        }
+        _ => chars.clear(),
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Random text of at most `max_length` characters, made of the characters of `sample`
+pub fn random_input(rng: &mut Rng, sample: &str, max_length: usize) -> String {
+    let chars: Vec<char> = sample.chars().collect();
+    (0..rng.range(0..max_length + 1))
+        .map(|_| random_char(rng, &chars))
+        .collect()
+}
+
+/// Remove parts of an input as long as it still fails.
+///
+/// Chunks of characters are removed, from half of the input down to a single character, so the
+/// result cannot be reduced further by removing one character.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut current: Vec<char> = input.chars().collect();
+
+    let mut chunk = current.len().div_ceil(2);
+    while chunk > 0 {
+        let mut start = 0;
+        while start < current.len() {
+            let end = (start + chunk).min(current.len());
+            let candidate: String = current[..start].iter().chain(&current[end..]).collect();
+            if fails(&candidate) {
+                current = candidate.chars().collect();
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    current.into_iter().collect()
+}
+
+/// Run a property on an input, a panic is a failure of the property
+fn run(property: &impl Fn(&str) -> Result<(), String>, input: &str) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| property(input))).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("panicked: {message}"))
+    })
+}
+
+/// Check a property on every input, the first failure is shrunk and returned
+pub fn check<I>(inputs: I, property: impl Fn(&str) -> Result<(), String>) -> Result<(), Failure>
+where
+    I: IntoIterator<Item = String>,
+{
+    for input in inputs {
+        if let Err(message) = run(&property, &input) {
+            let shrunk = shrink(&input, |candidate| run(&property, candidate).is_err());
+            let message = run(&property, &shrunk).err().unwrap_or(message);
+            return Err(Failure { input, shrunk, message });
+        }
+    }
+
+    Ok(())
+}
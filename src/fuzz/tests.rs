@@ -0,0 +1,229 @@
+use std::fmt::Debug;
+
+use crate::errors::ParseError;
+use crate::fuzz::{check, mutate, random_input, shrink};
+use crate::generate::Rng;
+use crate::models::AdventSolution;
+use crate::registry;
+use crate::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16,
+    day17, day18, day19, day20, day21, day22, day23, day24, day25,
+};
+
+/// Number of inputs checked for each day
+const CASES: u64 = 100;
+
+/// Generated inputs with a few mutations, one input out of ten is random text
+fn fuzzed_inputs(day: usize) -> impl Iterator<Item = String> {
+    let solver = registry::get(day).unwrap().solver();
+    (0..CASES).map(move |seed| {
+        let mut rng = Rng::new(seed);
+        let mut input = solver.generate(&mut rng, Some(10)).unwrap();
+        if seed % 10 == 9 {
+            return random_input(&mut rng, &input, 40);
+        }
+
+        for _ in 0..rng.range(1..4) {
+            input = mutate(&mut rng, &input);
+        }
+        input
+    })
+}
+
+/// The parser of a day returns a model or an error located in the input, it never panics
+fn parses_or_fails_cleanly(day: usize) -> impl Fn(&str) -> Result<(), String> {
+    move |input| {
+        let Err(error) = registry::get(day).unwrap().solver().parse(input.to_string()) else {
+            return Ok(());
+        };
+
+        let lines = input.lines().count();
+        if error.day != Some(day) {
+            return Err(format!("the error has no day: {error:?}"));
+        }
+        if error.line == 0 || error.line > lines + 1 || error.column == 0 {
+            return Err(format!("the error is outside of the input: {error:?}"));
+        }
+        if error.column > error.text.chars().count() + 1 {
+            return Err(format!("the error is outside of its line: {error:?}"));
+        }
+        error.to_string();
+        Ok(())
+    }
+}
+
+/// Parsing the serialized model gives the same model
+fn round_trips<S: AdventSolution>() -> impl Fn(&str) -> Result<(), String>
+where
+    S::Parsed: PartialEq + Debug,
+{
+    |input| {
+        let Ok(model) = S::parse(input.to_string()) else {
+            return Ok(());
+        };
+
+        let serialized = S::serialize(&model);
+        match S::parse(serialized.clone()) {
+            Ok(parsed) if parsed == model => Ok(()),
+            Ok(parsed) => Err(format!("{serialized:?} is parsed as {parsed:?} instead of {model:?}")),
+            Err(error) => Err(format!("{serialized:?} cannot be parsed: {error}")),
+        }
+    }
+}
+
+/// Check the round trip of the models of the listed days, the models are compared so each day needs
+/// its solver type
+macro_rules! check_round_trips {
+    ($($day:literal => $solver:ty),* $(,)?) => {
+        [$(($day, check(fuzzed_inputs($day), round_trips::<$solver>()))),*]
+    };
+}
+
+/// An invariant holds for the model of every input that can be parsed
+fn holds<T>(
+    parse: fn(&str) -> Result<T, ParseError>,
+    invariant: fn(&T) -> Result<(), String>,
+) -> impl Fn(&str) -> Result<(), String> {
+    move |input| parse(input).map_or(Ok(()), |model| invariant(&model))
+}
+
+/// Every update has a middle page
+fn updates_have_a_middle_page(manual_updates: &day05::ManualUpdates) -> Result<(), String> {
+    match manual_updates
+        .updates
+        .iter()
+        .find(|update| update.len().is_multiple_of(2))
+    {
+        Some(update) => Err(format!("update {update:?} has no middle page")),
+        None => Ok(()),
+    }
+}
+
+/// Keys have a full last line and an empty first line, locks are the opposite
+fn schematics_are_keys_or_locks((keys, locks): &(Vec<u64>, Vec<u64>)) -> Result<(), String> {
+    // Each line of a schematic is a byte, the first line being the most significant one
+    let first_and_last = |schematic: &u64| ((schematic >> 48) & 0x1F, schematic & 0x1F);
+    if let Some(key) = keys.iter().find(|key| first_and_last(key) != (0, 0x1F)) {
+        return Err(format!("{key:#x} is not a key"));
+    }
+    if let Some(lock) = locks.iter().find(|lock| first_and_last(lock) != (0x1F, 0)) {
+        return Err(format!("{lock:#x} is not a lock"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_shrink() {
+    assert_eq!(shrink("abc\nxdef", |input| input.contains('x')), "x");
+    assert_eq!(shrink("12,34,56", |input| input.matches(',').count() == 2), ",,");
+    assert_eq!(shrink("abc", |_| false), "abc");
+}
+
+#[test]
+fn test_mutate() {
+    let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\n";
+    let mutated: Vec<_> = (0..100).map(|seed| mutate(&mut Rng::new(seed), input)).collect();
+
+    assert_eq!(mutated[7], mutate(&mut Rng::new(7), input));
+    assert!(mutated.iter().filter(|mutated| *mutated != input).count() > 90);
+}
+
+#[test]
+fn test_check_shrinks_failures() {
+    let inputs = ["12".to_string(), "4a56".to_string()];
+    let failure = check(inputs, |input| {
+        input
+            .chars()
+            .all(|c| c.is_ascii_digit())
+            .then_some(())
+            .ok_or(format!("{input} is not a number"))
+    })
+    .unwrap_err();
+
+    assert_eq!(failure.input, "4a56");
+    assert_eq!(failure.shrunk, "a");
+    assert_eq!(failure.message, "a is not a number");
+
+    // A panic is a failure too
+    let failure = check(["4a56".to_string()], |input| {
+        input.parse::<u32>().unwrap();
+        Ok(())
+    })
+    .unwrap_err();
+    assert!(failure.message.starts_with("panicked"), "{}", failure.message);
+}
+
+#[test]
+fn test_parsers_on_fuzzed_inputs() {
+    for info in registry::all() {
+        if let Err(failure) = check(fuzzed_inputs(info.day), parses_or_fails_cleanly(info.day)) {
+            panic!(
+                "day {} parser fails on {:?}: {}",
+                info.day, failure.shrunk, failure.message
+            );
+        }
+    }
+}
+
+#[test]
+fn test_models_round_trip() {
+    let results = check_round_trips![
+        1 => day01::Day01,
+        2 => day02::Day02,
+        3 => day03::Day03,
+        4 => day04::Day04,
+        5 => day05::Day05,
+        6 => day06::Day06,
+        7 => day07::Day07,
+        8 => day08::Day08,
+        9 => day09::Day09,
+        10 => day10::Day10,
+        11 => day11::Day11,
+        12 => day12::Day12,
+        13 => day13::Day13,
+        14 => day14::Day14,
+        15 => day15::Day15,
+        16 => day16::Day16,
+        17 => day17::Day17,
+        18 => day18::Day18,
+        19 => day19::Day19,
+        20 => day20::Day20,
+        21 => day21::Day21,
+        22 => day22::Day22,
+        23 => day23::Day23,
+        24 => day24::Day24,
+        25 => day25::Day25,
+    ];
+
+    for (day, result) in results {
+        if let Err(failure) = result {
+            panic!(
+                "day {day} model does not round trip with {:?}: {}",
+                failure.shrunk, failure.message
+            );
+        }
+    }
+}
+
+#[test]
+fn test_models_invariants() {
+    let results = [
+        (
+            5,
+            check(fuzzed_inputs(5), holds(day05::parse, updates_have_a_middle_page)),
+        ),
+        (
+            25,
+            check(fuzzed_inputs(25), holds(day25::parse, schematics_are_keys_or_locks)),
+        ),
+    ];
+
+    for (day, result) in results {
+        if let Err(failure) = result {
+            panic!(
+                "day {day} model is invalid with {:?}: {}",
+                failure.shrunk, failure.message
+            );
+        }
+    }
+}
@@ -0,0 +1,72 @@
+mod rng;
+
+#[cfg(test)]
+mod tests;
+
+pub use rng::Rng;
+
+use crate::grid::{Coordinates, Direction, Grid};
+
+/// Lower case letters used to build random names
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// Join lines with a line ending after each of them, like the puzzle inputs
+pub fn lines<I: IntoIterator<Item = String>>(lines: I) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Random string of `length` characters picked in `alphabet`
+pub fn word(rng: &mut Rng, alphabet: &[u8], length: usize) -> String {
+    (0..length).map(|_| *rng.choose(alphabet) as char).collect()
+}
+
+/// Generate a maze where `true` cells are walls.
+///
+/// Floor cells have odd coordinates and are all connected, the outer border is made of walls. A
+/// perfect maze has a single path between two cells, a `loops` fraction of the remaining inner walls
+/// between two floor cells is removed to create alternative paths.
+pub fn maze(rng: &mut Rng, width: usize, height: usize, loops: f64) -> Grid<bool> {
+    // Walls are placed on even coordinates, the grid needs an odd size
+    let width = (width.max(3) - 1) | 1;
+    let height = (height.max(3) - 1) | 1;
+    let mut grid = Grid::new(width, height, true);
+
+    // Carve the maze with a depth first search from a random cell
+    let start = Coordinates::new(rng.range(0..width / 2) * 2 + 1, rng.range(0..height / 2) * 2 + 1);
+    grid[start] = false;
+    let mut stack = vec![start];
+    while let Some(&current) = stack.last() {
+        let mut directions = Direction::ALL;
+        rng.shuffle(&mut directions);
+
+        let next = directions.into_iter().find_map(|direction| {
+            let wall = current.step(direction)?;
+            let next = wall.step(direction)?;
+            (next.x < width - 1 && next.y < height - 1 && grid[next]).then_some((wall, next))
+        });
+        match next {
+            Some((wall, next)) => {
+                grid[wall] = false;
+                grid[next] = false;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Remove some walls separating two corridors
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let position = Coordinates::new(x, y);
+            let horizontal = x.is_multiple_of(2) && !y.is_multiple_of(2);
+            let vertical = !x.is_multiple_of(2) && y.is_multiple_of(2);
+            if grid[position] && (horizontal || vertical) && rng.chance(loops) {
+                grid[position] = false;
+            }
+        }
+    }
+
+    grid
+}
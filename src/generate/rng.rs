@@ -0,0 +1,69 @@
+use std::ops::{Range, RangeInclusive};
+
+/// Small deterministic pseudo random number generator (SplitMix64).
+///
+/// It is not suitable for cryptography but it is fast and the same seed always produces the same
+/// inputs, which makes a failing input easy to reproduce.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "cannot pick a value below 0");
+
+        // Reject the values of the last incomplete interval so that every value is equally likely
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// Uniform value in a non empty range
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "cannot pick a value in an empty range");
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+
+    /// Uniform signed value in a non empty inclusive range
+    pub fn signed(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick a value in an empty range");
+        start.wrapping_add(self.below(end.abs_diff(start) + 1) as i64)
+    }
+
+    /// Return true with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // 53 bits is the precision of a f64
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Pick one item of a non empty slice
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    /// Shuffle a slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::generate::{lines, maze, word, Rng};
+use crate::grid::Coordinates;
+use crate::pathfinding::bfs;
+use crate::registry;
+
+#[test]
+fn test_rng_is_deterministic() {
+    let first: Vec<_> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+    let second: Vec<_> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+    let other: Vec<_> = (0..5).scan(Rng::new(43), |rng, _| Some(rng.next_u64())).collect();
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn test_rng_ranges() {
+    let mut rng = Rng::new(0);
+    for _ in 0..1000 {
+        assert!((3..7).contains(&rng.range(3..7)));
+        assert!((-5..=5).contains(&rng.signed(-5..=5)));
+    }
+    assert_eq!(rng.range(4..5), 4);
+    assert_eq!(rng.signed(i64::MIN..=i64::MIN), i64::MIN);
+    assert!(!rng.chance(0.0));
+    assert!(rng.chance(1.0));
+
+    // Every value of a small range is eventually picked
+    let mut seen = [false; 6];
+    for _ in 0..1000 {
+        seen[rng.range(0..6)] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+fn test_shuffle() {
+    let mut rng = Rng::new(7);
+    let mut items: Vec<_> = (0..20).collect();
+    rng.shuffle(&mut items);
+
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort_unstable();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_word_and_lines() {
+    let mut rng = Rng::new(1);
+    let word = word(&mut rng, b"ab", 8);
+    assert_eq!(word.len(), 8);
+    assert!(word.chars().all(|c| c == 'a' || c == 'b'));
+
+    assert_eq!(lines(["a".to_string(), "b".to_string()]), "a\nb\n");
+}
+
+#[test]
+fn test_maze() {
+    let mut rng = Rng::new(3);
+    let maze = maze(&mut rng, 22, 15, 0.0);
+
+    // The size is rounded down to an odd number
+    assert_eq!((maze.width(), maze.height()), (21, 15));
+    assert!(maze.rows().next().unwrap().iter().all(|wall| *wall));
+    assert!(maze.column(0).all(|wall| *wall));
+
+    // Every floor cell can be reached from any other and a perfect maze has no loop
+    let floor: Vec<_> = maze.find_all(&false).collect();
+    let search = bfs(
+        Coordinates::new(1, 1),
+        |node| maze.neighbours(*node).filter(|next| !maze[*next]),
+        |_| false,
+    );
+    assert_eq!(search.costs().len(), floor.len());
+    assert_eq!(floor.len(), 10 * 7 + 10 * 7 - 1);
+}
+
+#[test]
+fn test_every_day_generates_valid_inputs() {
+    // The smallest sizes, where generators are the most likely to fail, and a typical one
+    for size in [0, 1, 2, 3, 5, 20] {
+        for info in registry::all() {
+            let input = info
+                .solver()
+                .generate(&mut Rng::new(info.day as u64), Some(size))
+                .unwrap_or_else(|| panic!("day {} has no generator", info.day));
+            let same_input = info
+                .solver()
+                .generate(&mut Rng::new(info.day as u64), Some(size))
+                .unwrap();
+            assert_eq!(input, same_input, "day {} generator is not deterministic", info.day);
+
+            // Solving the input must not panic either
+            let parsed = match info.solver().parse(input) {
+                Ok(parsed) => parsed,
+                Err(error) => panic!("invalid input generated with size {size} for {error}"),
+            };
+            let prepared = parsed.prepare();
+            prepared.solve_part_one();
+            prepared.solve_part_two();
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+
+/// Position of a cell in a grid, `x` is the column and `y` the line
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Coordinates {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Display for Coordinates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}:{})", self.x, self.y)
+    }
+}
+
+impl Coordinates {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Move by the given offset, `None` if the result would be negative
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Move one step in the given direction, `None` if the result would be negative
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Coordinates {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Move one step in a direction.
+///
+/// This panics on overflow in debug, it is meant for grids padded with a border that cannot be crossed.
+impl Add<Direction> for Coordinates {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        match rhs {
+            Direction::Up => Self::new(self.x, self.y - 1),
+            Direction::Right => Self::new(self.x + 1, self.y),
+            Direction::Down => Self::new(self.x, self.y + 1),
+            Direction::Left => Self::new(self.x - 1, self.y),
+        }
+    }
+}
+
+#[derive(Hash, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Up => write!(f, "Up"),
+            Direction::Right => write!(f, "Right"),
+            Direction::Down => write!(f, "Down"),
+            Direction::Left => write!(f, "Left"),
+        }
+    }
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Position of the direction in `ALL`, to be used as an index in per direction tables
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Column and line offsets of a step in this direction
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
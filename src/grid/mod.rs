@@ -0,0 +1,242 @@
+mod coordinates;
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+pub use coordinates::{Coordinates, Direction};
+
+use crate::errors::ParseError;
+use crate::parsing::{ensure_rectangular, trim_trailing_lines};
+
+/// Offsets of the 8 neighbours of a cell, clockwise from up
+const NEIGHBOURS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Rectangular 2D grid stored line by line.
+///
+/// Cells can be accessed with `Coordinates` or line by line with `grid[y][x]`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its lines, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid lines must have the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parse a grid where each character is a cell.
+    ///
+    /// `parse_cell` receives the position and the character of each cell and returns an error
+    /// message if the character is invalid. Empty lines at the end of the input are ignored.
+    pub fn parse<F>(input: &str, mut parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(Coordinates, char) -> Result<T, String>,
+    {
+        let input = trim_trailing_lines(input);
+        ensure_rectangular(input)?;
+
+        let mut cells = vec![];
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let cell = parse_cell(Coordinates::new(x, y), c)
+                    .map_err(|message| ParseError::at_line(y, x, line, message))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: cells.len() / height,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coordinates: Coordinates) -> bool {
+        coordinates.x < self.width && coordinates.y < self.height
+    }
+
+    pub fn get(&self, coordinates: Coordinates) -> Option<&T> {
+        self.contains(coordinates)
+            .then(|| &self.cells[coordinates.y * self.width + coordinates.x])
+    }
+
+    pub fn get_mut(&mut self, coordinates: Coordinates) -> Option<&mut T> {
+        self.contains(coordinates)
+            .then(|| &mut self.cells[coordinates.y * self.width + coordinates.x])
+    }
+
+    /// Iterate over the neighbours up, right, down and left of a cell that are inside the grid
+    pub fn neighbours(&self, coordinates: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| coordinates.step(direction))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Iterate over the neighbours of a cell including the diagonal ones that are inside the grid
+    pub fn neighbours_diagonal(&self, coordinates: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| coordinates.offset(dx, dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    /// Iterate over every cell with its position, line by line
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Coordinates::new(i % width, i / width), cell))
+    }
+
+    /// Iterate over the positions of every cell, line by line
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinates> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Coordinates::new(i % width, i / width))
+    }
+
+    /// Create a grid of the same size by transforming each cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid filled with `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Surround the grid with `size` cells of `value` on each side.
+    ///
+    /// A border simplifies algorithms that look at neighbours, the coordinates of the existing cells
+    /// are shifted by `size` on both axes.
+    pub fn padded(self, size: usize, value: T) -> Self {
+        let width = self.width + 2 * size;
+        let mut padded = Self::new(width, self.height + 2 * size, value);
+        for (y, row) in self.rows().enumerate() {
+            let start = (y + size) * width + size;
+            padded.cells[start..start + self.width].clone_from_slice(row);
+        }
+        padded
+    }
+
+    /// Remove `size` cells on each side, this is the opposite of `padded`
+    pub fn unpadded(&self, size: usize) -> Self {
+        let width = self.width.saturating_sub(2 * size);
+        let height = self.height.saturating_sub(2 * size);
+        Self {
+            width,
+            height,
+            cells: self
+                .rows()
+                .skip(size)
+                .take(height)
+                .flat_map(|row| row[size..size + width].iter().cloned())
+                .collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Position of the first cell equal to `value`, line by line
+    pub fn find(&self, value: &T) -> Option<Coordinates> {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(coordinates, _)| coordinates)
+    }
+
+    /// Positions of every cell equal to `value`
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coordinates> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(coordinates, _)| coordinates)
+    }
+}
+
+impl<T> Index<Coordinates> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Coordinates) -> &Self::Output {
+        assert!(self.contains(index), "{index} is outside of the grid");
+        &self.cells[index.y * self.width + index.x]
+    }
+}
+
+impl<T> IndexMut<Coordinates> for Grid<T> {
+    fn index_mut(&mut self, index: Coordinates) -> &mut Self::Output {
+        assert!(self.contains(index), "{index} is outside of the grid");
+        &mut self.cells[index.y * self.width + index.x]
+    }
+}
+
+/// Access a line of the grid
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &Self::Output {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut Self::Output {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::grid::{Coordinates, Direction, Grid};
+
+static INPUT: &str = "ab.\n.c.\n..d\n#..";
+
+fn parse_chars(input: &str) -> Grid<char> {
+    Grid::parse(input, |_, c| Ok(c)).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = parse_chars(INPUT);
+
+    assert_eq!((grid.width(), grid.height()), (3, 4));
+    assert_eq!(grid[Coordinates::new(1, 0)], 'b');
+    assert_eq!(grid[2][2], 'd');
+    assert_eq!(grid.get(Coordinates::new(3, 0)), None);
+    assert_eq!(grid.to_string(), INPUT.to_string() + "\n");
+
+    // Empty lines at the end of the input are ignored
+    assert_eq!(parse_chars(&format!("{INPUT}\n\n\r\n")), grid);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Grid::parse("..\n.x", |_, c| {
+        if c == '.' {
+            Ok(0)
+        } else {
+            Err(format!("unknown tile '{c}'"))
+        }
+    })
+    .unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+
+    let error = Grid::parse("...\n..", |_, c| Ok(c)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+}
+
+#[test]
+fn test_padded() {
+    let grid = parse_chars("ab\ncd").padded(1, '.');
+
+    assert_eq!(grid.to_string(), "....\n.ab.\n.cd.\n....\n");
+    assert_eq!(grid.find(&'d'), Some(Coordinates::new(2, 2)));
+    assert_eq!(grid.unpadded(1), parse_chars("ab\ncd"));
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = parse_chars(INPUT);
+
+    let corner: Vec<_> = grid.neighbours(Coordinates::new(0, 0)).collect();
+    assert_eq!(corner, vec![Coordinates::new(1, 0), Coordinates::new(0, 1)]);
+    assert_eq!(grid.neighbours(Coordinates::new(1, 1)).count(), 4);
+
+    assert_eq!(grid.neighbours_diagonal(Coordinates::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours_diagonal(Coordinates::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours_diagonal(Coordinates::new(2, 3)).count(), 3);
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = parse_chars(INPUT);
+
+    assert_eq!(grid.rows().nth(3), Some(['#', '.', '.'].as_slice()));
+    assert_eq!(grid.column(2).collect::<String>(), "..d.");
+    assert_eq!(grid.column(0).rev().collect::<String>(), "#..a");
+    assert_eq!(grid.find_all(&'.').count(), 7);
+}
+
+#[test]
+fn test_directions() {
+    let start = Coordinates::new(1, 1);
+
+    assert_eq!(start + Direction::Up, Coordinates::new(1, 0));
+    assert_eq!(Coordinates::new(0, 0).step(Direction::Left), None);
+    assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Right.opposite(), Direction::Left);
+    assert_eq!(Direction::Down.index(), 2);
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests;
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::grid::Grid;
+
+/// Colour of a pixel
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GREY: Color = Color::rgb(96, 96, 96);
+    pub const DARK_GREY: Color = Color::rgb(32, 32, 32);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(64, 200, 64);
+    pub const YELLOW: Color = Color::rgb(230, 190, 40);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const MAGENTA: Color = Color::rgb(211, 54, 130);
+
+    /// Colours that are easy to tell apart, used by `Color::indexed`
+    const PALETTE: [Color; 12] = [
+        Color::rgb(230, 25, 75),
+        Color::rgb(60, 180, 75),
+        Color::rgb(255, 225, 25),
+        Color::rgb(0, 130, 200),
+        Color::rgb(245, 130, 48),
+        Color::rgb(145, 30, 180),
+        Color::rgb(70, 240, 240),
+        Color::rgb(240, 50, 230),
+        Color::rgb(210, 245, 60),
+        Color::rgb(250, 190, 212),
+        Color::rgb(0, 128, 128),
+        Color::rgb(170, 110, 40),
+    ];
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Pick a colour for the `index`th item of a set, such as a region, colours cycle after 12 items
+    pub fn indexed(index: usize) -> Self {
+        Self::PALETTE[index % Self::PALETTE.len()]
+    }
+
+    /// Hexadecimal notation used by SVG, `#rrggbb`
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// Binary portable pixmap, readable by most image viewers and converters
+    Ppm,
+    Svg,
+}
+
+impl ImageFormat {
+    /// Guess the format from the extension of a file, `None` if the extension is unknown
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Write an image where each cell of the grid is a square of `scale` pixels
+pub fn write_image<W: Write>(image: &Grid<Color>, format: ImageFormat, scale: usize, out: &mut W) -> io::Result<()> {
+    match format {
+        ImageFormat::Ppm => write_ppm(image, scale, out),
+        ImageFormat::Svg => write_svg(image, scale, out),
+    }
+}
+
+/// Write an image in the binary PPM format (P6)
+pub fn write_ppm<W: Write>(image: &Grid<Color>, scale: usize, out: &mut W) -> io::Result<()> {
+    let scale = scale.max(1);
+    writeln!(out, "P6")?;
+    writeln!(out, "{} {}", image.width() * scale, image.height() * scale)?;
+    writeln!(out, "255")?;
+
+    let mut line = Vec::with_capacity(image.width() * scale * 3);
+    for row in image.rows() {
+        line.clear();
+        for color in row {
+            for _ in 0..scale {
+                line.extend([color.r, color.g, color.b]);
+            }
+        }
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write an image in the SVG format.
+///
+/// Consecutive cells of the same colour on a line are merged in a single rectangle to keep the
+/// file small.
+pub fn write_svg<W: Write>(image: &Grid<Color>, scale: usize, out: &mut W) -> io::Result<()> {
+    let scale = scale.max(1);
+    let width = image.width() * scale;
+    let height = image.height() * scale;
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+    )?;
+
+    for (y, row) in image.rows().enumerate() {
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{}"/>"#,
+                x * scale,
+                y * scale,
+                run.len() * scale,
+                run[0].to_hex()
+            )?;
+            x += run.len();
+        }
+    }
+
+    writeln!(out, "</svg>")
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use crate::grid::Grid;
+use crate::image::{write_ppm, write_svg, Color, ImageFormat};
+
+#[test]
+fn test_image_format() {
+    assert_eq!(ImageFormat::from_path(Path::new("tree.ppm")), Some(ImageFormat::Ppm));
+    assert_eq!(
+        ImageFormat::from_path(Path::new("out/regions.SVG")),
+        Some(ImageFormat::Svg)
+    );
+    assert_eq!(ImageFormat::from_path(Path::new("tree.png")), None);
+    assert_eq!(ImageFormat::from_path(Path::new("tree")), None);
+}
+
+#[test]
+fn test_write_ppm() {
+    let image = Grid::from_rows(vec![vec![Color::BLACK, Color::WHITE]]);
+
+    let mut out = vec![];
+    write_ppm(&image, 2, &mut out).unwrap();
+
+    let mut expected = b"P6\n4 2\n255\n".to_vec();
+    for _ in 0..2 {
+        expected.extend([0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]);
+    }
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_write_svg() {
+    let image = Grid::from_rows(vec![vec![Color::RED, Color::RED, Color::BLUE]]);
+
+    let mut out = vec![];
+    write_svg(&image, 10, &mut out).unwrap();
+    let svg = String::from_utf8(out).unwrap();
+
+    // The two red cells are merged
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="10""#));
+    assert!(svg.contains(r##"<rect x="0" y="0" width="20" height="10" fill="#dc322f"/>"##));
+    assert!(svg.contains(r##"<rect x="20" y="0" width="10" height="10" fill="#268bd2"/>"##));
+    assert!(svg.ends_with("</svg>\n"));
+}
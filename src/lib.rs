@@ -24,6 +24,29 @@ pub mod day23;
 pub mod day24;
 pub mod day25;
 
+pub mod baseline;
+pub mod bench;
 pub mod common;
+pub mod crosscheck;
+#[allow(dead_code)]
 mod dayxx;
+pub mod errors;
+#[cfg(test)]
+mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod logging;
+pub mod memory;
 pub mod models;
+pub mod parsing;
+pub mod pathfinding;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod solve;
+pub mod verify;
+pub mod visualize;
+pub mod watch;
+
+pub use crate::solve::{solve, DayReport};
@@ -0,0 +1,67 @@
+//! Lightweight logging of solver internals, written to the standard error.
+//!
+//! Logs are disabled by default, the binary enables them with `-v` (debug) and `-vv` (trace). The
+//! message arguments are only evaluated when the level is enabled, so expensive dumps are free otherwise.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Level {
+    Off = 0,
+    Debug = 1,
+    Trace = 2,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+impl Level {
+    /// Level matching a `-v` flag count
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        match verbosity {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Off => "off",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::logging::enabled($level) {
+            eprintln!("[{} {}] {}", $level.name(), module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Log a step of a solver
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::logging::log!($crate::logging::Level::Debug, $($arg)+)
+    };
+}
+
+/// Log the details of a solver step, such as intermediate states
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::logging::log!($crate::logging::Level::Trace, $($arg)+)
+    };
+}
+
+// The macros are only meant for the solvers, they stay out of the public API of the crate
+pub(crate) use {debug, log, trace};
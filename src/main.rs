@@ -1,61 +1,130 @@
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use std::time::Instant;
-
-use advent_2024::day01::Day01;
-use advent_2024::day02::Day02;
-use advent_2024::day03::Day03;
-use advent_2024::day04::Day04;
-use advent_2024::day05::Day05;
-use advent_2024::day06::Day06;
-use advent_2024::day07::Day07;
-use advent_2024::day08::Day08;
-use advent_2024::day09::Day09;
-use advent_2024::day10::Day10;
-use advent_2024::day11::Day11;
-use advent_2024::day12::Day12;
-use advent_2024::day13::Day13;
-use advent_2024::day14::Day14;
-use advent_2024::day15::Day15;
-use advent_2024::day16::Day16;
-use advent_2024::day17::Day17;
-use advent_2024::day18::Day18;
-use advent_2024::day19::Day19;
-use advent_2024::day20::Day20;
-use advent_2024::day21::Day21;
-use advent_2024::day22::Day22;
-use advent_2024::day23::Day23;
-use advent_2024::day24::Day24;
-use advent_2024::day25::Day25;
-use advent_2024::models::AdventSolution;
-use clap::{Args, Parser, Subcommand};
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::slice;
+use std::time::Duration;
+
+use advent_2024::baseline::{compare, PhaseChange};
+use advent_2024::bench::{bench_day, BenchConfig};
+use advent_2024::crosscheck::crosscheck;
+use advent_2024::generate::Rng;
+use advent_2024::image::{write_image, ImageFormat};
+use advent_2024::logging::{set_level, Level};
+use advent_2024::memory;
+use advent_2024::memory::{format_bytes, CountingAllocator, MemoryStats};
+use advent_2024::models::DynSolution;
+use advent_2024::registry;
+use advent_2024::registry::SolverInfo;
+use advent_2024::runner::output::{from_csv, to_csv, to_json, to_json_array};
+use advent_2024::runner::{run_day, run_day_part, run_days, DayJob, DayResult, Part, RunDaySolution};
+use advent_2024::scaffold::scaffold_day;
+use advent_2024::verify::{check_day, parse_answers};
+use advent_2024::visualize::{play, PlayConfig};
+use advent_2024::watch::Watcher;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use colored::Colorize;
 use comfy_table::presets::UTF8_FULL;
 use comfy_table::{Cell, ContentArrangement, Table};
 
-#[derive(Parser, Debug, Eq, PartialEq, Clone)]
+/// Only counts allocations when memory reporting is enabled
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug, PartialEq, Clone)]
 struct Cli {
-    /// Enable verbose display
-    #[arg(short, long, default_value_t = false)]
-    pub verbose: bool,
+    /// Display solver internals on the standard error, -v for debug logs, -vv for trace logs
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
 
     #[command(subcommand)]
     command: Commands,
 }
 
-#[derive(Subcommand, Debug, Eq, PartialEq, Clone)]
+#[derive(Subcommand, Debug, PartialEq, Clone)]
 enum Commands {
     /// Run every solution
     All(AllArgs),
 
     /// Run a specific day
     Day(DayArgs),
+
+    /// Run days several times and display timing statistics
+    Bench(BenchArgs),
+
+    /// Check answers against an answers file
+    Verify(VerifyArgs),
+
+    /// List available days
+    List,
+
+    /// Create the module of a new day from the dayxx template
+    New(NewArgs),
+
+    /// Animate the simulation of a day in the terminal
+    Visualize(VisualizeArgs),
+
+    /// Save a picture of the state of a day to a PPM or SVG file
+    Export(ExportArgs),
+
+    /// Print a random input for a day
+    Generate(GenerateArgs),
+
+    /// Compare a day with its naive reference solver and report the first diverging input
+    ///
+    /// Only the days with an optimised algorithm have a reference solver: 06, 11, 13, 21 and 22.
+    Crosscheck(CrosscheckArgs),
 }
 
-#[derive(Args, Debug, Eq, PartialEq, Clone)]
+#[derive(ValueEnum, Debug, Default, Eq, PartialEq, Clone, Copy)]
+enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Args, Debug, PartialEq, Clone)]
 struct AllArgs {
     /// If set, use input present in the inputs directory
     #[arg(short, long, default_value_t = false)]
     pub use_real_input: bool,
+
+    /// Directory containing the inputs, every `dayNN` and `dayNN_*` file is run
+    #[arg(short, long, conflicts_with = "use_real_input")]
+    pub inputs_dir: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Save answers and timings to this file, in CSV
+    #[arg(long)]
+    pub save_baseline: Option<PathBuf>,
+
+    /// Compare answers and timings with a baseline saved by --save-baseline
+    #[arg(long)]
+    pub compare: Option<PathBuf>,
+
+    /// Percentage above which a slower phase is reported as a regression
+    #[arg(long, default_value_t = 10.0, requires = "compare")]
+    pub threshold: f64,
+
+    /// Number of days run concurrently
+    #[arg(short, long, default_value_t = 1)]
+    pub jobs: usize,
+
+    /// Time limit of each day in seconds, after which the day is reported as timed out
+    #[arg(short, long)]
+    pub timeout: Option<f64>,
+
+    /// Count allocations of each phase, days are then run one at a time.
+    ///
+    /// Not available with a timeout: a timed-out day keeps allocating while the next ones are measured.
+    #[arg(short, long, default_value_t = false, conflicts_with = "timeout")]
+    pub memory: bool,
 }
 
 #[derive(Args, Debug, Eq, PartialEq, Clone)]
@@ -63,142 +132,872 @@ struct DayArgs {
     /// Day
     pub day: usize,
 
-    /// File to parse
+    /// File to parse, `-` to read the standard input
     pub path: PathBuf,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Run the day again every time the input file changes
+    #[arg(short, long, default_value_t = false)]
+    pub watch: bool,
+
+    /// Also watch the files of this directory, the day runs on every file that changes
+    #[arg(long, requires = "watch")]
+    pub watch_dir: Option<PathBuf>,
+
+    /// Delay between two checks of the watched files, in milliseconds
+    #[arg(long, default_value_t = 500, requires = "watch")]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct VerifyArgs {
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
+
+    /// Answers file, answers.txt in the input directory if not set
+    #[arg(short, long)]
+    pub answers: Option<PathBuf>,
 }
 
-struct RunDaySolution {
-    day: usize,
-    part_01_sol: i128,
-    part_02_sol: i128,
-    parse_time: u128,
-    prep_time: u128,
-    part_01_time: u128,
-    part_02_time: u128,
+#[derive(Args, Debug, PartialEq, Clone)]
+struct NewArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=99))]
+    pub day: u8,
+
+    /// Puzzle title displayed by the list command, "Day <day>" if not set
+    #[arg(short, long)]
+    pub title: Option<String>,
+
+    /// Root of the crate to add the day to
+    #[arg(short, long, default_value = ".")]
+    pub root: PathBuf,
 }
 
-fn run_day(day: usize, solution: &mut Box<dyn AdventSolution>, input: String) -> RunDaySolution {
-    // Parse the data
-    let now = Instant::now();
-    solution.parse(input);
-    let parse_time = now.elapsed().as_micros();
+#[derive(Args, Debug, PartialEq, Clone)]
+struct BenchArgs {
+    /// Day to benchmark, every day if not set
+    pub day: Option<usize>,
 
-    // Prepare the parsed_data
-    let now = Instant::now();
-    solution.prepare();
-    let prep_time = now.elapsed().as_micros();
+    /// File to parse, the day input is used if not set
+    #[arg(short, long, requires = "day")]
+    pub path: Option<PathBuf>,
 
-    // Solve part one
-    let now = Instant::now();
-    let part_01_sol = solution.solve_part_one();
-    let part_01_time = now.elapsed().as_micros();
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
 
-    // Solve part two
-    let now = Instant::now();
-    let part_02_sol = solution.solve_part_two();
-    let part_02_time = now.elapsed().as_micros();
+    /// Number of runs done before measuring
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: usize,
 
-    RunDaySolution {
-        day,
-        part_01_sol,
-        part_02_sol,
-        parse_time,
-        prep_time,
-        part_01_time,
-        part_02_time,
-    }
+    /// Number of measured runs
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub iterations: usize,
+
+    /// Run each day for this many seconds instead of a fixed number of runs
+    #[arg(short, long)]
+    pub duration: Option<f64>,
 }
 
-fn main() {
-    let mut solvers: Vec<Box<dyn AdventSolution>> = vec![
-        Box::<Day01>::default(),
-        Box::<Day02>::default(),
-        Box::<Day03>::default(),
-        Box::<Day04>::default(),
-        Box::<Day05>::default(),
-        Box::<Day06>::default(),
-        Box::<Day07>::default(),
-        Box::<Day08>::default(),
-        Box::<Day09>::default(),
-        Box::<Day10>::default(),
-        Box::<Day11>::default(),
-        Box::<Day12>::default(),
-        Box::<Day13>::default(),
-        Box::<Day14>::default(),
-        Box::<Day15>::default(),
-        Box::<Day16>::default(),
-        Box::<Day17>::default(),
-        Box::<Day18>::default(),
-        Box::<Day19>::default(),
-        Box::<Day20>::default(),
-        Box::<Day21>::default(),
-        Box::<Day22>::default(),
-        Box::<Day23>::default(),
-        Box::<Day24>::default(),
-        Box::<Day25>::default(),
-    ];
+#[derive(Args, Debug, PartialEq, Clone)]
+struct VisualizeArgs {
+    /// Day to animate
+    pub day: usize,
 
-    let arguments = Cli::parse();
+    /// File to parse, the day input is used if not set
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
 
-    match arguments.command {
-        Commands::All(all_args) => {
-            let mut table = Table::new();
-            let mut total_time: u128 = 0;
-            table
-                .load_preset(UTF8_FULL)
-                .set_content_arrangement(ContentArrangement::Dynamic)
-                .set_header(vec![
-                    "Day",
-                    "Part 1 solution",
-                    "Part 2 solution",
-                    "Parse time",
-                    "Prep time",
-                    "Part 1 time",
-                    "Part 2 time",
-                    "Tot time",
-                ]);
-            for (i, solver) in solvers.iter_mut().enumerate() {
-                let input = if all_args.use_real_input {
-                    fs::read_to_string(format!("inputs/day{:0>2}", i + 1)).unwrap()
-                } else {
-                    fs::read_to_string(format!("input_examples/day{:0>2}", i + 1)).unwrap()
-                };
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
 
-                let solution = run_day(i + 1, solver, input);
-                let day_time = solution.parse_time + solution.prep_time + solution.part_01_time + solution.part_02_time;
-                total_time += day_time;
-                table.add_row(vec![
+    /// Time between two frames in milliseconds
+    #[arg(short, long, default_value_t = 50)]
+    pub delay: u64,
+
+    /// Only display one frame out of this many, the last one is always displayed
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub every: u64,
+}
+
+#[derive(Args, Debug, PartialEq, Clone)]
+struct ExportArgs {
+    /// Day to export
+    pub day: usize,
+
+    /// Image file to write, its format is deduced from the extension (.ppm or .svg)
+    pub output: PathBuf,
+
+    /// File to parse, the day input is used if not set
+    #[arg(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
+
+    /// Export the state after this many steps of the simulation instead of the final state
+    #[arg(short, long)]
+    pub step: Option<usize>,
+
+    /// Size in pixels of each cell of the grid
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..))]
+    pub scale: u64,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    pub day: usize,
+
+    /// Seed of the random generator, the same seed always gives the same input
+    #[arg(short, long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Size of the input (number of lines, side of the grid, ...), as big as the real input if not set
+    #[arg(short = 'n', long)]
+    pub size: Option<usize>,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct CrosscheckArgs {
+    /// Day to check
+    pub day: usize,
+
+    /// Input files to check, random inputs are generated if not set
+    #[arg(short, long, num_args = 1..)]
+    pub paths: Vec<PathBuf>,
+
+    /// Number of random inputs to check
+    #[arg(short = 'n', long, default_value_t = 100)]
+    pub inputs: u64,
+
+    /// Seed of the first random input, the next inputs use the following seeds
+    #[arg(short, long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Size of the random inputs, small inputs keep the reference solvers fast
+    #[arg(long, default_value_t = 10)]
+    pub size: usize,
+
+    /// Write the first diverging input to this file
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Read an input file, or the standard input for `-`, exiting with a readable message if it cannot be read
+fn read_input(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        if let Err(error) = io::stdin().read_to_string(&mut input) {
+            eprintln!("error: unable to read the standard input: {error}");
+            process::exit(1)
+        }
+        return input;
+    }
+
+    fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("error: unable to read {}: {error}", path.display());
+        process::exit(1)
+    })
+}
+
+/// Run a day, exiting with the parse error diagnostic if its input is invalid
+fn run_day_or_exit(day: usize, solver: &dyn DynSolution, input: String) -> RunDaySolution {
+    run_day(day, solver, input).unwrap_or_else(|error| {
+        eprintln!("error: invalid input for {error}");
+        process::exit(1)
+    })
+}
+
+/// Display memory statistics of a phase on three lines
+fn format_memory(stats: &MemoryStats) -> String {
+    format!(
+        "{} allocs\n{} total\n{} peak",
+        stats.allocations,
+        format_bytes(stats.allocated_bytes),
+        format_bytes(stats.peak_bytes)
+    )
+}
+
+fn display_table(results: &[DayResult]) {
+    let mut table = Table::new();
+    let mut header = vec![
+        "Day",
+        "Input",
+        "Part 1 solution",
+        "Part 2 solution",
+        "Parse time",
+        "Prep time",
+        "Part 1 time",
+        "Part 2 time",
+        "Tot time",
+    ];
+    let show_memory = results
+        .iter()
+        .any(|result| matches!(result, DayResult::Solved(solution) if solution.memory.is_some()));
+    if show_memory {
+        header.extend(["Parse memory", "Prep memory", "Part 1 memory", "Part 2 memory"]);
+    }
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header);
+    let mut total_time: u128 = 0;
+    for result in results {
+        match result {
+            DayResult::Solved(solution) => {
+                total_time += solution.total_time();
+                let mut row = vec![
                     Cell::new(solution.day),
-                    Cell::new(solution.part_01_sol),
-                    Cell::new(solution.part_02_sol),
+                    Cell::new(&solution.input),
+                    Cell::new(&solution.part_01_sol),
+                    Cell::new(&solution.part_02_sol),
                     Cell::new(solution.parse_time),
                     Cell::new(solution.prep_time),
                     Cell::new(solution.part_01_time),
                     Cell::new(solution.part_02_time),
-                    Cell::new(day_time),
+                    Cell::new(solution.total_time()),
+                ];
+                if let Some(memory) = &solution.memory {
+                    row.extend(
+                        [memory.parse, memory.prepare, memory.part_one, memory.part_two]
+                            .iter()
+                            .map(|stats| Cell::new(format_memory(stats))),
+                    );
+                }
+                table.add_row(row);
+            }
+            DayResult::SkewedTimings(solution) => {
+                table.add_row(vec![
+                    Cell::new(solution.day),
+                    Cell::new(&solution.input),
+                    Cell::new(&solution.part_01_sol),
+                    Cell::new(&solution.part_02_sol),
+                ]);
+            }
+            DayResult::TimedOut { day, input } => {
+                table.add_row(vec![
+                    Cell::new(day),
+                    Cell::new(input),
+                    Cell::new("timed out"),
+                    Cell::new("timed out"),
+                ]);
+            }
+            DayResult::Panicked { day, input } => {
+                table.add_row(vec![
+                    Cell::new(day),
+                    Cell::new(input),
+                    Cell::new("panicked"),
+                    Cell::new("panicked"),
                 ]);
             }
-            println!("Advent of code 2024 solutions (every time is displayed in microseconds):");
-            println!("{table}");
-            println!("Total execution time (excluding file loading time): {total_time} microseconds");
+            // Invalid inputs stop the run before the display
+            DayResult::InvalidInput(_) => (),
         }
-        Commands::Day(day_args) => {
-            let input = fs::read_to_string(day_args.path).unwrap();
-            let solution = run_day(day_args.day, &mut solvers[day_args.day - 1], input);
-
-            // Display the result
-            println!(
-                "Day {:0>2}, results: {:>14}, {:>14}, \
-                parse_time: {:>10} us, prep_time: {:>10} us, \
-                part_01_time: {:>10} us, part_02_time: {:>10} us",
-                solution.day,
-                solution.part_01_sol,
-                solution.part_02_sol,
-                solution.parse_time,
-                solution.prep_time,
-                solution.part_01_time,
-                solution.part_02_time
+    }
+
+    println!("Advent of code 2024 solutions (every time is displayed in microseconds):");
+    println!("{table}");
+    println!("Total execution time (excluding file loading time): {total_time} microseconds");
+}
+
+fn display_line(solution: &RunDaySolution) {
+    println!(
+        "Day {:0>2}, results: {:>14}, {:>14}, \
+        parse_time: {:>10} us, prep_time: {:>10} us, \
+        part_01_time: {:>10} us, part_02_time: {:>10} us",
+        solution.day,
+        solution.part_01_sol,
+        solution.part_02_sol,
+        solution.parse_time,
+        solution.prep_time,
+        solution.part_01_time,
+        solution.part_02_time
+    );
+}
+
+fn display_solution(solution: &RunDaySolution, format: OutputFormat) {
+    match format {
+        OutputFormat::Table => display_line(solution),
+        OutputFormat::Json => println!("{}", to_json(solution)),
+        OutputFormat::Csv => println!("{}", to_csv(slice::from_ref(solution))),
+    }
+}
+
+/// Display the answers and the timings that changed since the previous run of the same input
+fn display_changes(previous: &RunDaySolution, solution: &RunDaySolution) {
+    let Some(comparison) = compare(slice::from_ref(previous), slice::from_ref(solution), 0.0).pop() else {
+        return;
+    };
+
+    for change in &comparison.answer_changes {
+        let message = format!(
+            "  part {} answer changed from {} to {}",
+            change.part, change.baseline, change.current
+        );
+        println!("{}", message.yellow().bold());
+    }
+
+    let format_changes = |changes: &[PhaseChange]| {
+        changes
+            .iter()
+            .map(|change| {
+                let delta = change.current as i128 - change.baseline as i128;
+                format!("{} {delta:+} us ({:+.1}%)", change.phase, change.change_percent())
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if !comparison.improvements.is_empty() {
+        println!(
+            "{}",
+            format!("  faster: {}", format_changes(&comparison.improvements)).green()
+        );
+    }
+    if !comparison.regressions.is_empty() {
+        println!(
+            "{}",
+            format!("  slower: {}", format_changes(&comparison.regressions)).red()
+        );
+    }
+}
+
+/// Run a day on a watched input. Errors are displayed without exiting since the file may be edited again.
+fn run_watched_day(
+    info: &SolverInfo,
+    path: &Path,
+    part: Option<Part>,
+    format: OutputFormat,
+    previous_runs: &mut HashMap<PathBuf, RunDaySolution>,
+) {
+    let input = match fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: unable to read {}: {error}", path.display());
+            return;
+        }
+    };
+
+    // A panic is reported by the default hook, the watch goes on. Solvers have no state that a panic
+    // could leave broken.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| run_day_part(info.day, info.solver(), input, part)));
+    let mut solution = match result {
+        Ok(Ok(solution)) => solution,
+        Ok(Err(error)) => {
+            eprintln!("error: invalid input for {error}");
+            return;
+        }
+        Err(_) => return,
+    };
+    solution.input = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+
+    display_solution(&solution, format);
+    if let (Some(previous), OutputFormat::Table) = (previous_runs.get(path), format) {
+        display_changes(previous, &solution);
+    }
+    previous_runs.insert(path.to_path_buf(), solution);
+}
+
+/// Run a day, then run it again every time its input or a file of the watched directory changes
+fn run_watch(info: &SolverInfo, day_args: &DayArgs, part: Option<Part>) -> ! {
+    if day_args.path == Path::new("-") {
+        eprintln!("error: the standard input cannot be watched");
+        process::exit(1)
+    }
+
+    let mut targets = vec![day_args.path.clone()];
+    targets.extend(day_args.watch_dir.clone());
+    let mut watcher = Watcher::new(targets);
+    let mut previous_runs = HashMap::new();
+
+    run_watched_day(info, &day_args.path, part, day_args.format, &mut previous_runs);
+    loop {
+        for path in watcher.wait(Duration::from_millis(day_args.interval)) {
+            // Removed files are skipped, editors may remove a file before writing it again
+            if path.is_file() {
+                // Written to the standard error to keep the JSON and CSV outputs parseable
+                eprintln!("{}", format!("{} changed", path.display()).dimmed());
+                run_watched_day(info, &path, part, day_args.format, &mut previous_runs);
+            }
+        }
+    }
+}
+
+/// Report answers and timings that changed since a baseline, exiting with an error on regressions
+fn compare_with_baseline(path: &Path, solutions: &[RunDaySolution], threshold: f64) {
+    let baseline = from_csv(&read_input(path)).unwrap_or_else(|error| {
+        eprintln!("error: invalid baseline {}, {error}", path.display());
+        process::exit(1)
+    });
+
+    let mut regressions = 0;
+    println!("Comparison with {} (threshold: {threshold}%):", path.display());
+    for comparison in compare(&baseline, solutions, threshold) {
+        for change in &comparison.answer_changes {
+            let message = format!(
+                "Day {:0>2} ({}): part {} answer changed from {} to {}",
+                comparison.day, comparison.input, change.part, change.baseline, change.current
+            );
+            println!("{}", message.red().bold());
+        }
+        for change in &comparison.regressions {
+            let message = format!(
+                "Day {:0>2} ({}): {} time regressed from {} us to {} us ({:+.1}%)",
+                comparison.day,
+                comparison.input,
+                change.phase,
+                change.baseline,
+                change.current,
+                change.change_percent()
+            );
+            println!("{}", message.red());
+        }
+        for change in &comparison.improvements {
+            let message = format!(
+                "Day {:0>2} ({}): {} time improved from {} us to {} us ({:+.1}%)",
+                comparison.day,
+                comparison.input,
+                change.phase,
+                change.baseline,
+                change.current,
+                change.change_percent()
             );
+            println!("{}", message.green());
+        }
+
+        if comparison.has_regression() {
+            regressions += 1;
+        }
+    }
+
+    if regressions > 0 {
+        println!("{}", format!("{regressions} day(s) regressed").red().bold());
+        process::exit(1)
+    }
+    println!("{}", "No regression".green());
+}
+
+fn run_bench(bench_args: BenchArgs) {
+    let infos = match bench_args.day {
+        Some(day) => match registry::get(day) {
+            Some(info) => vec![info],
+            None => {
+                eprintln!("error: no solver for day {day}");
+                process::exit(1)
+            }
+        },
+        None => registry::all().iter().collect(),
+    };
+
+    let config = BenchConfig {
+        warmup: bench_args.warmup,
+        iterations: bench_args.iterations,
+        duration: bench_args.duration.map(Duration::from_secs_f64),
+    };
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Day", "Phase", "Runs", "Min", "Median", "Mean", "P95"]);
+
+    for info in infos {
+        let input = match (&bench_args.path, bench_args.use_real_input) {
+            (Some(path), _) => read_input(path),
+            (None, true) => read_input(info.real_input),
+            (None, false) => read_input(info.example_input),
+        };
+
+        let result = bench_day(info, &input, &config).unwrap_or_else(|error| {
+            eprintln!("error: invalid input for {error}");
+            process::exit(1)
+        });
+
+        for (phase, stats) in [
+            ("parse", result.parse),
+            ("prepare", result.prepare),
+            ("part 1", result.part_one),
+            ("part 2", result.part_two),
+        ] {
+            table.add_row(vec![
+                Cell::new(result.day),
+                Cell::new(phase),
+                Cell::new(result.iterations),
+                Cell::new(stats.min),
+                Cell::new(stats.median),
+                Cell::new(stats.mean),
+                Cell::new(stats.p95),
+            ]);
+        }
+    }
+
+    println!("Advent of code 2024 benchmark (every time is displayed in nanoseconds):");
+    println!("{table}");
+}
+
+fn run_verify(verify_args: VerifyArgs) {
+    let (input_directory, answers_path) = if verify_args.use_real_input {
+        ("inputs", PathBuf::from("inputs/answers.txt"))
+    } else {
+        ("input_examples", PathBuf::from("input_examples/answers.txt"))
+    };
+    let answers_path = verify_args.answers.unwrap_or(answers_path);
+
+    let answers = parse_answers(&read_input(&answers_path)).unwrap_or_else(|error| {
+        eprintln!("error: invalid answers file {}, {error}", answers_path.display());
+        process::exit(1)
+    });
+
+    let mut days: Vec<_> = answers.iter().map(|answer| answer.day).collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Day", "Part", "Expected", "Answer", "Result", "Time"]);
+
+    let mut failures = 0;
+    for day in days {
+        let Some(info) = registry::get(day) else {
+            eprintln!("error: no solver for day {day} listed in {}", answers_path.display());
+            process::exit(1)
+        };
+
+        let input = read_input(Path::new(input_directory).join(format!("day{day:0>2}")));
+        let solution = run_day_or_exit(day, info.solver(), input);
+
+        for check in check_day(&solution, &answers) {
+            if !check.passed() {
+                failures += 1;
+            }
+            table.add_row(vec![
+                Cell::new(check.day),
+                Cell::new(check.part),
+                Cell::new(&check.expected),
+                Cell::new(&check.actual),
+                Cell::new(if check.passed() { "PASS" } else { "FAIL" }),
+                Cell::new(check.time),
+            ]);
+        }
+    }
+
+    println!("Advent of code 2024 verification (every time is displayed in microseconds):");
+    println!("{table}");
+    if failures > 0 {
+        println!("{failures} part(s) do not match the expected answer");
+        process::exit(1)
+    }
+}
+
+fn run_visualize(visualize_args: VisualizeArgs) {
+    let Some(info) = registry::get(visualize_args.day) else {
+        eprintln!("error: no solver for day {}", visualize_args.day);
+        process::exit(1)
+    };
+
+    let input = match (&visualize_args.path, visualize_args.use_real_input) {
+        (Some(path), _) => read_input(path),
+        (None, true) => read_input(info.real_input),
+        (None, false) => read_input(info.example_input),
+    };
+
+    let parsed = info.solver().parse(input).unwrap_or_else(|error| {
+        eprintln!("error: invalid input for {error}");
+        process::exit(1)
+    });
+
+    let Some(frames) = parsed.visualize() else {
+        eprintln!("error: day {} has no visualization", info.day);
+        process::exit(1)
+    };
+
+    let config = PlayConfig {
+        delay: Duration::from_millis(visualize_args.delay),
+        every: visualize_args.every as usize,
+        clear: true,
+    };
+    if let Err(error) = play(frames, &config, &mut io::stdout().lock()) {
+        eprintln!("error: unable to display the visualization: {error}");
+        process::exit(1)
+    }
+}
+
+fn run_export(export_args: ExportArgs) {
+    let Some(info) = registry::get(export_args.day) else {
+        eprintln!("error: no solver for day {}", export_args.day);
+        process::exit(1)
+    };
+
+    let Some(format) = ImageFormat::from_path(&export_args.output) else {
+        eprintln!(
+            "error: unknown image format for {}, expected a .ppm or .svg file",
+            export_args.output.display()
+        );
+        process::exit(1)
+    };
+
+    let input = match (&export_args.path, export_args.use_real_input) {
+        (Some(path), _) => read_input(path),
+        (None, true) => read_input(info.real_input),
+        (None, false) => read_input(info.example_input),
+    };
+
+    let parsed = info.solver().parse(input).unwrap_or_else(|error| {
+        eprintln!("error: invalid input for {error}");
+        process::exit(1)
+    });
+
+    let Some(image) = parsed.export(export_args.step) else {
+        eprintln!("error: day {} has nothing to export", info.day);
+        process::exit(1)
+    };
+
+    let written = fs::File::create(&export_args.output).and_then(|file| {
+        let mut out = io::BufWriter::new(file);
+        write_image(&image, format, export_args.scale as usize, &mut out)?;
+        out.flush()
+    });
+    if let Err(error) = written {
+        eprintln!("error: unable to write {}: {error}", export_args.output.display());
+        process::exit(1)
+    }
+}
+
+fn run_generate(generate_args: GenerateArgs) {
+    let Some(info) = registry::get(generate_args.day) else {
+        eprintln!("error: no solver for day {}", generate_args.day);
+        process::exit(1)
+    };
+
+    let mut rng = Rng::new(generate_args.seed);
+    let Some(input) = info.solver().generate(&mut rng, generate_args.size) else {
+        eprintln!("error: day {} has no input generator", info.day);
+        process::exit(1)
+    };
+
+    print!("{input}");
+}
+
+fn run_crosscheck(crosscheck_args: CrosscheckArgs) {
+    let Some(info) = registry::get(crosscheck_args.day) else {
+        eprintln!("error: no solver for day {}", crosscheck_args.day);
+        process::exit(1)
+    };
+
+    // Inputs are generated one at a time since they are only needed until a divergence is found
+    let inputs: Box<dyn Iterator<Item = (String, String)>> = if crosscheck_args.paths.is_empty() {
+        let (day, size) = (info.day, crosscheck_args.size);
+        Box::new(
+            (crosscheck_args.seed..crosscheck_args.seed + crosscheck_args.inputs).map(move |seed| {
+                let Some(input) = info.solver().generate(&mut Rng::new(seed), Some(size)) else {
+                    eprintln!("error: day {day} has no input generator, use --paths");
+                    process::exit(1)
+                };
+                (format!("generate {day} --seed {seed} --size {size}"), input)
+            }),
+        )
+    } else {
+        Box::new(
+            crosscheck_args
+                .paths
+                .iter()
+                .map(|path| (path.display().to_string(), read_input(path))),
+        )
+    };
+
+    let mut checked = 0;
+    for (name, input) in inputs {
+        let result = crosscheck(info, input.clone()).unwrap_or_else(|error| {
+            eprintln!("error: invalid input for {error}");
+            process::exit(1)
+        });
+        if result.checked.is_empty() {
+            eprintln!("error: day {} has no reference solver, see crosscheck --help", info.day);
+            process::exit(1)
+        }
+
+        if !result.passed() {
+            println!("Day {:0>2} diverges on `{name}`:", info.day);
+            for divergence in &result.divergences {
+                let part = if divergence.part == Part::One { 1 } else { 2 };
+                println!(
+                    "  part {part}: reference {}, solver {}",
+                    divergence.expected, divergence.actual
+                );
+            }
+            if let Some(path) = &crosscheck_args.output {
+                if let Err(error) = fs::write(path, input) {
+                    eprintln!("error: unable to write {}: {error}", path.display());
+                }
+            }
+            process::exit(1)
+        }
+        checked += 1;
+    }
+
+    println!(
+        "Day {:0>2} matches its reference solver on {checked} input(s)",
+        info.day
+    );
+}
+
+fn main() {
+    let arguments = Cli::parse();
+    set_level(Level::from_verbosity(arguments.verbose));
+
+    match arguments.command {
+        Commands::All(all_args) => {
+            let inputs_dir = match (&all_args.inputs_dir, all_args.use_real_input) {
+                (Some(inputs_dir), _) => inputs_dir.clone(),
+                (None, true) => PathBuf::from("inputs"),
+                (None, false) => PathBuf::from("input_examples"),
+            };
+
+            let mut jobs = vec![];
+            for info in registry::all() {
+                let paths = info.find_inputs(&inputs_dir).unwrap_or_else(|error| {
+                    eprintln!("error: unable to read {}: {error}", inputs_dir.display());
+                    process::exit(1)
+                });
+                if paths.is_empty() {
+                    eprintln!(
+                        "warning: no input found for day {:0>2} in {}",
+                        info.day,
+                        inputs_dir.display()
+                    );
+                }
+
+                for path in paths {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                    jobs.push(DayJob::new(info, name, read_input(&path)));
+                }
+            }
+            // Allocation counters are global, days must not overlap to be measured
+            let threads = if all_args.memory {
+                memory::set_enabled(true);
+                1
+            } else {
+                all_args.jobs
+            };
+            let results = run_days(jobs, threads, all_args.timeout.map(Duration::from_secs_f64));
+
+            let mut solutions = vec![];
+            let mut failures = 0;
+            for result in &results {
+                match result {
+                    DayResult::Solved(solution) => solutions.push(solution.clone()),
+                    // Skewed timings would spoil the output and the baseline
+                    DayResult::SkewedTimings(solution) => eprintln!(
+                        "warning: day {:0>2} on {} ran alongside a timed-out day, its timings are left out",
+                        solution.day, solution.input
+                    ),
+                    DayResult::InvalidInput(error) => {
+                        eprintln!("error: invalid input for {error}");
+                        process::exit(1)
+                    }
+                    DayResult::TimedOut { day, input } => {
+                        failures += 1;
+                        eprintln!("error: day {day:0>2} timed out on {input}");
+                    }
+                    DayResult::Panicked { day, input } => {
+                        failures += 1;
+                        eprintln!("error: day {day:0>2} panicked on {input}");
+                    }
+                }
+            }
+
+            match all_args.format {
+                OutputFormat::Table => display_table(&results),
+                OutputFormat::Json => println!("{}", to_json_array(&solutions)),
+                OutputFormat::Csv => println!("{}", to_csv(&solutions)),
+            }
+
+            if let Some(path) = &all_args.save_baseline {
+                if let Err(error) = fs::write(path, to_csv(&solutions) + "\n") {
+                    eprintln!("error: unable to write {}: {error}", path.display());
+                    process::exit(1)
+                }
+            }
+
+            if let Some(path) = &all_args.compare {
+                compare_with_baseline(path, &solutions, all_args.threshold);
+            }
+
+            if failures > 0 {
+                process::exit(1)
+            }
+        }
+        Commands::Day(day_args) => {
+            let Some(info) = registry::get(day_args.day) else {
+                let available = registry::all()
+                    .iter()
+                    .map(|info| info.day.to_string())
+                    .collect::<Vec<_>>();
+                eprintln!(
+                    "error: no solver for day {}, available days: {}",
+                    day_args.day,
+                    available.join(", ")
+                );
+                process::exit(1)
+            };
+
+            let part = day_args.part.map(|part| if part == 1 { Part::One } else { Part::Two });
+            if day_args.watch {
+                run_watch(info, &day_args, part);
+            }
+
+            let input = read_input(&day_args.path);
+            let mut solution = run_day_part(info.day, info.solver(), input, part).unwrap_or_else(|error| {
+                eprintln!("error: invalid input for {error}");
+                process::exit(1)
+            });
+            solution.input = day_args
+                .path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            display_solution(&solution, day_args.format);
+        }
+        Commands::Bench(bench_args) => run_bench(bench_args),
+        Commands::Verify(verify_args) => run_verify(verify_args),
+        Commands::Visualize(visualize_args) => run_visualize(visualize_args),
+        Commands::Export(export_args) => run_export(export_args),
+        Commands::Generate(generate_args) => run_generate(generate_args),
+        Commands::Crosscheck(crosscheck_args) => run_crosscheck(crosscheck_args),
+        Commands::List => {
+            for info in registry::all() {
+                println!("Day {:0>2}: {}", info.day, info.title);
+            }
+        }
+        Commands::New(new_args) => {
+            let day = new_args.day as usize;
+            let title = new_args.title.unwrap_or_else(|| format!("Day {day}"));
+            match scaffold_day(&new_args.root, day, &title) {
+                Ok(created) => {
+                    for path in created {
+                        println!("created {}", path.display());
+                    }
+                }
+                Err(error) => {
+                    eprintln!("error: unable to create day {day}: {error}");
+                    process::exit(1)
+                }
+            }
         }
     }
 }
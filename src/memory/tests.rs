@@ -0,0 +1,8 @@
+use crate::memory::format_bytes;
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(12), "12 B");
+    assert_eq!(format_bytes(2048), "2.0 KiB");
+    assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+}
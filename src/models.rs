@@ -1,9 +1,228 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::errors::ParseError;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::Color;
+
+/// Solver of a day.
+///
+/// The input goes through three steps: `parse` turns it into `Parsed`, `prepare` consumes it to compute
+/// what both parts share and the parts borrow the result. Solvers hold no state, so a part cannot be
+/// solved before the input is parsed and several inputs can be solved at the same time.
 pub trait AdventSolution {
-    fn parse(&mut self, _data: String) {}
+    type Parsed: Send + Sync + 'static;
+    type Prepared: Send + Sync + 'static;
+
+    fn parse(data: String) -> Result<Self::Parsed, ParseError>;
+
+    /// Puzzle input in the format of the real one, parsing it gives back `parsed`
+    fn serialize(parsed: &Self::Parsed) -> String;
+
+    fn prepare(parsed: Self::Parsed) -> Self::Prepared;
+
+    fn solve_part_one(prepared: &Self::Prepared) -> Answer;
+
+    fn solve_part_two(prepared: &Self::Prepared) -> Answer;
+
+    /// Answer of the first part computed by a naive implementation, used as an oracle for the
+    /// optimised one. `None` if the day has no reference solver.
+    fn reference_part_one(_parsed: &Self::Parsed) -> Option<Answer> {
+        None
+    }
 
-    fn prepare(&mut self) {}
+    /// Answer of the second part computed by a naive implementation, see `reference_part_one`
+    fn reference_part_two(_parsed: &Self::Parsed) -> Option<Answer> {
+        None
+    }
+
+    /// Frames of the puzzle simulation rendered for the terminal, `None` if the day has nothing to
+    /// animate
+    fn visualize(_parsed: &Self::Parsed) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        None
+    }
+
+    /// Picture of the puzzle state after `step` steps of the simulation, or of its most interesting
+    /// state if `step` is `None`. `None` if the day has nothing to export.
+    fn export(_parsed: &Self::Parsed, _step: Option<usize>) -> Option<Grid<Color>> {
+        None
+    }
+
+    /// Random input that respects the constraints of the puzzle, `None` if the day has no generator.
+    ///
+    /// `size` scales the input (number of lines, side of the grid, ...), inputs are as big as the
+    /// real ones if it is not set.
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe version of `AdventSolution`, so that solvers of different days can be stored together.
+///
+/// `Solver` implements it for every `AdventSolution`, the parsed and prepared inputs are boxed.
+pub trait DynSolution: Send + Sync {
+    fn parse(&self, data: String) -> Result<Box<dyn DynParsed>, ParseError>;
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String>;
+}
 
-    fn solve_part_one(&self) -> i128;
+impl std::fmt::Debug for dyn DynSolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DynSolution")
+    }
+}
+
+/// Parsed input of a `DynSolution`
+pub trait DynParsed: Send + Sync {
+    fn prepare(self: Box<Self>) -> Box<dyn DynPrepared>;
+
+    fn serialize(&self) -> String;
+
+    fn reference_part_one(&self) -> Option<Answer>;
+
+    fn reference_part_two(&self) -> Option<Answer>;
+
+    fn visualize(&self) -> Option<Box<dyn Iterator<Item = String> + '_>>;
+
+    fn export(&self, step: Option<usize>) -> Option<Grid<Color>>;
+}
+
+/// Prepared input of a `DynSolution`
+pub trait DynPrepared: Send + Sync {
+    fn solve_part_one(&self) -> Answer;
+
+    fn solve_part_two(&self) -> Answer;
+}
+
+/// Adapter from an `AdventSolution` to a `DynSolution`
+pub struct Solver<S>(PhantomData<fn() -> S>);
+
+impl<S> Solver<S> {
+    pub const NEW: Self = Solver(PhantomData);
+}
+
+struct Parsed<S: AdventSolution>(S::Parsed);
+
+struct Prepared<S: AdventSolution>(S::Prepared);
+
+impl<S: AdventSolution + 'static> DynSolution for Solver<S> {
+    fn parse(&self, data: String) -> Result<Box<dyn DynParsed>, ParseError> {
+        Ok(Box::new(Parsed::<S>(S::parse(data)?)))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        // An empty input is never valid, the smallest input has a size of 1
+        S::generate(rng, size.map(|size| size.max(1)))
+    }
+}
+
+impl<S: AdventSolution + 'static> DynParsed for Parsed<S> {
+    fn prepare(self: Box<Self>) -> Box<dyn DynPrepared> {
+        Box::new(Prepared::<S>(S::prepare(self.0)))
+    }
+
+    fn serialize(&self) -> String {
+        S::serialize(&self.0)
+    }
+
+    fn reference_part_one(&self) -> Option<Answer> {
+        S::reference_part_one(&self.0)
+    }
+
+    fn reference_part_two(&self) -> Option<Answer> {
+        S::reference_part_two(&self.0)
+    }
+
+    fn visualize(&self) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        S::visualize(&self.0)
+    }
+
+    fn export(&self, step: Option<usize>) -> Option<Grid<Color>> {
+        S::export(&self.0, step)
+    }
+}
+
+impl<S: AdventSolution + 'static> DynPrepared for Prepared<S> {
+    fn solve_part_one(&self) -> Answer {
+        S::solve_part_one(&self.0)
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        S::solve_part_two(&self.0)
+    }
+}
+
+/// Answer of a puzzle part.
+///
+/// Most puzzles expect an integer but some of them expect a string (a program output, a password
+/// made of sorted names, ...) and the last day only has one part.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    /// Unsigned answer too big to fit in an `i64`
+    BigInteger(u128),
+    Text(String),
+    /// The part does not have an answer for this puzzle or for this input
+    NotApplicable,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Forward to the inner values so that width and alignment flags are respected
+        match self {
+            Answer::Integer(value) => value.fmt(f),
+            Answer::BigInteger(value) => value.fmt(f),
+            Answer::Text(value) => f.pad(value),
+            Answer::NotApplicable => f.pad("N/A"),
+        }
+    }
+}
+
+macro_rules! answer_from_signed {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::BigInteger(value as u128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_signed!(i32, i64);
+answer_from_unsigned!(u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
 
-    fn solve_part_two(&self) -> i128;
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::NotApplicable, Into::into)
+    }
 }
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests;
+
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{digit1, line_ending, one_of};
+use nom::combinator::{eof, map_res, opt, recognize, value};
+use nom::error::ErrorKind;
+use nom::multi::{many1_count, many_till, separated_list1};
+use nom::sequence::{pair, preceded, separated_pair, terminated};
+use nom::{IResult, Parser};
+
+use crate::errors::ParseError;
+use crate::grid::Grid;
+
+/// Check the result of a nom parser and ensure that the whole input was consumed.
+///
+/// The parser may have been applied to a sub slice of `input`, such as a section, errors are
+/// still located relatively to `input`.
+pub fn finish<T>(input: &str, result: IResult<&str, T>) -> Result<T, ParseError> {
+    match result {
+        Ok(("", value)) => Ok(value),
+        Ok((remaining, _)) => Err(ParseError::at(
+            input,
+            remaining,
+            "unable to parse this part of the input",
+        )),
+        Err(error) => Err(ParseError::from_nom(input, error)),
+    }
+}
+
+/// Apply a parser until the end of the input, at least once.
+///
+/// Unlike `many1`, which stops silently before an item it cannot parse, this reports the error of the
+/// failing item so that the diagnostic points at the faulty character instead of the start of its line.
+pub fn many1_complete<'a, T, F>(mut parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    move |input| {
+        let (remaining, (items, _)) = many_till(|input| parser.parse(input), eof).parse(input)?;
+        if items.is_empty() {
+            return Err(nom::Err::Error(nom::error::Error::new(input, ErrorKind::Many1)));
+        }
+        Ok((remaining, items))
+    }
+}
+
+/// Parse an integer with an optional `+` or `-` sign
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse).parse(input)
+}
+
+/// Parse an integer without sign
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse).parse(input)
+}
+
+/// Apply a parser to a whole line, the line ending is optional on the last line
+pub fn line<'a, T, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, T>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    terminated(parser, opt(line_ending))
+}
+
+/// Parse at least one item separated by `separator`
+pub fn list<'a, T, F>(separator: &'a str, parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    separated_list1(tag(separator), parser)
+}
+
+/// Parse a `key=value` pair, returning the value. The `=` can be replaced by any `separator`.
+pub fn key_value<'a, T, F>(key: &'a str, separator: &'a str, parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, T>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    preceded(pair(tag(key), tag(separator)), parser)
+}
+
+/// Parse a pair of signed integers such as coordinates, `12,-4` with `,` as `separator`
+pub fn number_pair<'a, T: FromStr>(separator: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, (T, T)> {
+    separated_pair(signed, tag(separator), signed)
+}
+
+/// Parse the empty lines separating two sections, this expects the previous line ending to be consumed
+pub fn blank_lines(input: &str) -> IResult<&str, ()> {
+    value((), many1_count(line_ending)).parse(input)
+}
+
+/// Split an input in sections separated by empty lines.
+///
+/// Each section is returned with the zero-based index of its first line. Sections are sub slices of
+/// the input without their last line ending, so that errors can be located with `ParseError::at`.
+pub fn sections(input: &str) -> Vec<(usize, &str)> {
+    let mut sections = vec![];
+    let mut current = None;
+    let mut offset = 0;
+    let mut end = 0;
+
+    for (y, line) in input.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.is_empty() {
+            if let Some((first_line, start)) = current.take() {
+                sections.push((first_line, &input[start..end]));
+            }
+        } else {
+            current.get_or_insert((y, offset));
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, start)) = current {
+        sections.push((first_line, &input[start..end]));
+    }
+
+    sections
+}
+
+/// Remove the empty lines at the end of a grid input
+pub fn trim_trailing_lines(input: &str) -> &str {
+    input.trim_end_matches(['\n', '\r'])
+}
+
+/// Ensure that a grid input is not empty and that all its lines have the same width, empty lines at
+/// the end of the input are ignored
+pub fn ensure_rectangular(input: &str) -> Result<(), ParseError> {
+    let mut lines = trim_trailing_lines(input).lines().enumerate();
+    let width = match lines.next() {
+        Some((_, line)) if !line.is_empty() => line.chars().count(),
+        _ => return Err(ParseError::at_line(0, 0, "", "expected a non empty grid")),
+    };
+
+    for (y, line) in lines {
+        let line_width = line.chars().count();
+        if line_width != width {
+            return Err(ParseError::at_line(
+                y,
+                line_width.min(width),
+                line,
+                format!("expected a line of {width} characters, found {line_width}"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a grid of characters
+pub fn char_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |_, c| Ok(c))
+}
+
+/// Parse a grid of single digit numbers
+pub fn digit_grid(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |_, c| {
+        c.to_digit(10).ok_or_else(|| "expected a digit".to_string())
+    })
+}
@@ -0,0 +1,56 @@
+use nom::bytes::complete::tag;
+use nom::sequence::separated_pair;
+use nom::Parser;
+
+use crate::parsing::{
+    blank_lines, digit_grid, finish, key_value, line, list, many1_complete, number_pair, sections, signed, unsigned,
+};
+
+#[test]
+fn test_numbers() {
+    assert_eq!(signed::<i64>("-12,3"), Ok((",3", -12)));
+    assert_eq!(signed::<i32>("+7"), Ok(("", 7)));
+    assert_eq!(unsigned::<u8>("42"), Ok(("", 42)));
+    assert!(unsigned::<u8>("-42").is_err());
+    assert!(unsigned::<u8>("256").is_err());
+}
+
+#[test]
+fn test_combinators() {
+    let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3";
+    let robots = finish(
+        input,
+        many1_complete(line(separated_pair(
+            key_value("p", "=", number_pair::<i64>(",")),
+            tag(" "),
+            key_value("v", "=", number_pair::<i64>(",")),
+        )))
+        .parse(input),
+    )
+    .unwrap();
+
+    assert_eq!(robots, vec![((0, 4), (3, -3)), ((6, 3), (-1, -3))]);
+}
+
+#[test]
+fn test_error_location() {
+    let input = "1,2,3\n4,x,6\n";
+    let error = finish(input, many1_complete(line(list(",", signed::<i64>))).parse(input)).unwrap_err();
+
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn test_sections() {
+    let input = "a\nb\n\n\nc\r\n\r\nd\n";
+    assert_eq!(sections(input), vec![(0, "a\nb"), (4, "c"), (6, "d")]);
+
+    let error = digit_grid(sections("1\n\n23\n4x").last().unwrap().1)
+        .unwrap_err()
+        .offset_lines(2);
+    assert_eq!((error.line, error.column), (4, 2));
+
+    let input = "1|2\n\n\n3\n";
+    let parsed = separated_pair(line(number_pair::<u32>("|")), blank_lines, line(unsigned::<u32>)).parse(input);
+    assert_eq!(parsed, Ok(("", ((1, 2), 3))));
+}
@@ -0,0 +1,217 @@
+mod min_scored;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use hashbrown::hash_map::Entry::{Occupied, Vacant};
+use hashbrown::{HashMap, HashSet};
+
+pub use min_scored::MinScored;
+
+/// Result of a search from a start node.
+///
+/// Every node reached by the search has a cost and the list of all its predecessors on a path of
+/// this cost, so that every optimal path can be reconstructed and not only the first one found.
+#[derive(Debug, Clone)]
+pub struct Search<N, K> {
+    start: N,
+    costs: HashMap<N, K>,
+    predecessors: HashMap<N, Vec<N>>,
+    /// First goal node reached, if any
+    pub goal: Option<N>,
+}
+
+impl<N, K> Search<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Copy,
+{
+    fn new(start: N, zero: K) -> Self {
+        Self {
+            start,
+            costs: [(start, zero)].into_iter().collect(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Cost of the cheapest path to `node`, `None` if the node was not reached
+    pub fn cost(&self, node: &N) -> Option<K> {
+        self.costs.get(node).copied()
+    }
+
+    /// Cost of the cheapest path to the goal, `None` if no goal was reached
+    pub fn goal_cost(&self) -> Option<K> {
+        self.goal.and_then(|goal| self.cost(&goal))
+    }
+
+    /// Cost of every node reached by the search
+    pub fn costs(&self) -> &HashMap<N, K> {
+        &self.costs
+    }
+
+    /// One of the cheapest paths from the start to `node`, both included
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(&node) {
+            return None;
+        }
+
+        let mut path = vec![node];
+        let mut current = node;
+        while current != self.start {
+            current = self.predecessors[&current][0];
+            path.push(current);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every node that is on at least one of the cheapest paths from the start to `node`
+    pub fn nodes_on_optimal_paths(&self, node: N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        if !self.costs.contains_key(&node) {
+            return nodes;
+        }
+
+        let mut to_visit = VecDeque::from([node]);
+        nodes.insert(node);
+        while let Some(current) = to_visit.pop_front() {
+            for previous in self.predecessors.get(&current).into_iter().flatten() {
+                if nodes.insert(*previous) {
+                    to_visit.push_back(*previous);
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// Record that `node` can be reached from `previous` with `cost`, returns true if the node must be
+    /// visited
+    fn relax(&mut self, node: N, previous: N, cost: K) -> bool
+    where
+        K: PartialOrd,
+    {
+        match self.costs.entry(node) {
+            Occupied(mut entry) => {
+                if *entry.get() < cost {
+                    // No need to visit nodes that we already reached through a shorter path
+                    false
+                } else if *entry.get() == cost {
+                    // Another optimal path, it does not need to be visited again
+                    self.predecessors.entry(node).or_default().push(previous);
+                    false
+                } else {
+                    entry.insert(cost);
+                    self.predecessors.insert(node, vec![previous]);
+                    true
+                }
+            }
+            Vacant(entry) => {
+                entry.insert(cost);
+                self.predecessors.insert(node, vec![previous]);
+                true
+            }
+        }
+    }
+}
+
+/// Find the cheapest paths from `start` with the A* algorithm.
+///
+/// `neighbours` returns the nodes reachable from a node with the cost of the move and `heuristic`
+/// estimates the remaining cost to a goal, it must never overestimate it and never decrease by more
+/// than the cost of a move. The search stops once a node matching `is_goal` is reached and every node
+/// that may still lead to it with the same cost is visited, so that its predecessors are complete.
+pub fn astar<N, K, FN, IN, FH, FG>(start: N, mut neighbours: FN, mut heuristic: FH, mut is_goal: FG) -> Search<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Copy + PartialOrd + Add<Output = K> + Default,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, K)>,
+    FH: FnMut(&N) -> K,
+    FG: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start, K::default());
+    let mut visit_next = BinaryHeap::new();
+    visit_next.push(MinScored(heuristic(&start), (K::default(), start)));
+
+    let mut goal_cost = None;
+    while let Some(MinScored(score, (node_cost, node))) = visit_next.pop() {
+        // Nodes with a higher score cannot be on a cheapest path to the goal
+        if goal_cost.is_some_and(|goal_cost| score > goal_cost) {
+            break;
+        }
+
+        // Skip outdated entries, the node was reached with a lower cost since
+        if search.costs[&node] < node_cost {
+            continue;
+        }
+
+        // Keep the first goal reached, other nodes with the same score may still be its predecessors
+        if is_goal(&node) {
+            if search.goal.is_none() {
+                search.goal = Some(node);
+                goal_cost = Some(node_cost);
+            }
+            continue;
+        }
+
+        for (next, move_cost) in neighbours(&node) {
+            let next_cost = node_cost + move_cost;
+            if search.relax(next, node, next_cost) {
+                visit_next.push(MinScored(next_cost + heuristic(&next), (next_cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+/// Find the cheapest paths from `start` with the Dijkstra algorithm.
+///
+/// Use `|_| false` as `is_goal` to compute the cost of every reachable node.
+pub fn dijkstra<N, K, FN, IN, FG>(start: N, neighbours: FN, is_goal: FG) -> Search<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Copy + PartialOrd + Add<Output = K> + Default,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, K)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| K::default(), is_goal)
+}
+
+/// Find the shortest paths from `start` when every move costs 1.
+///
+/// Use `|_| false` as `is_goal` to compute the distance of every reachable node.
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Search<N, usize>
+where
+    N: Copy + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start, 0);
+    let mut visit_next = VecDeque::from([start]);
+
+    while let Some(node) = visit_next.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            return search;
+        }
+
+        let next_cost = search.costs[&node] + 1;
+        for next in neighbours(&node) {
+            if search.relax(next, node, next_cost) {
+                visit_next.push_back(next);
+            }
+        }
+    }
+
+    search
+}
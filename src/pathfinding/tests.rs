@@ -0,0 +1,111 @@
+use crate::grid::{Coordinates, Grid};
+use crate::pathfinding::{astar, bfs, dijkstra};
+
+static MAZE: &str = "\
+.....
+.###.
+.#...
+.#.#.
+...#.";
+
+fn parse_maze() -> Grid<bool> {
+    Grid::parse(MAZE, |_, c| Ok(c == '#')).unwrap()
+}
+
+#[test]
+fn test_bfs() {
+    let maze = parse_maze();
+    let target = Coordinates::new(2, 2);
+
+    let search = bfs(
+        Coordinates::new(0, 0),
+        |node| maze.neighbours(*node).filter(|next| !maze[*next]).collect::<Vec<_>>(),
+        |node| *node == target,
+    );
+
+    assert_eq!(search.goal_cost(), Some(8));
+    let path = search.path_to(target).unwrap();
+    assert_eq!(path.len(), 9);
+    assert_eq!(path.first(), Some(&Coordinates::new(0, 0)));
+    assert_eq!(path.last(), Some(&target));
+
+    // Both sides of the maze are optimal, every free tile except the dead end is on one of them
+    assert_eq!(search.nodes_on_optimal_paths(target).len(), 16);
+}
+
+#[test]
+fn test_astar() {
+    let maze = parse_maze();
+    let target = Coordinates::new(2, 3);
+
+    let search = astar(
+        Coordinates::new(0, 0),
+        |node| {
+            maze.neighbours(*node)
+                .filter(|next| !maze[*next])
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        },
+        |node| node.manhattan_distance(&target),
+        |node| *node == target,
+    );
+
+    assert_eq!(search.goal_cost(), Some(7));
+    assert_eq!(search.path_to(target).unwrap().len(), 8);
+    assert_eq!(search.cost(&Coordinates::new(1, 1)), None);
+}
+
+#[test]
+fn test_astar_nodes_on_optimal_paths() {
+    // Every tile of an open grid is on a cheapest path between two opposite corners
+    let grid = Grid::new(8, 8, false);
+    let target = Coordinates::new(7, 7);
+    let neighbours = |node: &Coordinates| grid.neighbours(*node).map(|next| (next, 1)).collect::<Vec<_>>();
+
+    let search = astar(
+        Coordinates::new(0, 0),
+        neighbours,
+        |node| node.manhattan_distance(&target),
+        |node| *node == target,
+    );
+    assert_eq!(search.goal_cost(), Some(14));
+    assert_eq!(search.nodes_on_optimal_paths(target).len(), 64);
+
+    let search = dijkstra(Coordinates::new(0, 0), neighbours, |node| *node == target);
+    assert_eq!(search.nodes_on_optimal_paths(target).len(), 64);
+}
+
+#[test]
+fn test_dijkstra() {
+    // Going through b is shorter than the direct edge but c has two optimal paths
+    let edges = [
+        ('a', 'b', 1),
+        ('b', 'd', 1),
+        ('a', 'd', 5),
+        ('d', 'e', 2),
+        ('a', 'c', 3),
+        ('c', 'e', 1),
+    ];
+
+    let search = dijkstra(
+        'a',
+        |node| {
+            edges
+                .iter()
+                .filter(|edge| edge.0 == *node)
+                .map(|edge| (edge.1, edge.2))
+                .collect::<Vec<_>>()
+        },
+        |_| false,
+    );
+
+    assert_eq!(search.goal, None);
+    assert_eq!(search.cost(&'d'), Some(2));
+    assert_eq!(search.cost(&'e'), Some(4));
+    assert_eq!(search.costs().len(), 5);
+
+    let mut nodes: Vec<_> = search.nodes_on_optimal_paths('e').into_iter().collect();
+    nodes.sort();
+    assert_eq!(nodes, vec!['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(search.nodes_on_optimal_paths('z').len(), 0);
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::day16::Day16;
+use crate::day17::Day17;
+use crate::day18::Day18;
+use crate::day19::Day19;
+use crate::day20::Day20;
+use crate::day21::Day21;
+use crate::day22::Day22;
+use crate::day23::Day23;
+use crate::day24::Day24;
+use crate::day25::Day25;
+use crate::models::{DynSolution, Solver};
+
+/// Metadata of a registered solver
+#[derive(Debug, Clone, Copy)]
+pub struct SolverInfo {
+    pub day: usize,
+    pub title: &'static str,
+    /// Name of the input files, extra inputs add a suffix to it (`day03_2`, `day04_small`, ...)
+    pub input_name: &'static str,
+    /// Path of the example input, relative to the crate root
+    pub example_input: &'static str,
+    /// Path of the real input, relative to the crate root
+    pub real_input: &'static str,
+    /// Solvers have no state, the same one is shared by every run of the day
+    pub solver: &'static dyn DynSolution,
+}
+
+impl SolverInfo {
+    pub fn solver(&self) -> &'static dyn DynSolution {
+        self.solver
+    }
+
+    /// Find the inputs of this day in a directory: the main input first, then the extra ones sorted by name
+    pub fn find_inputs(&self, directory: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut inputs = vec![];
+        for entry in fs::read_dir(directory)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            let Some(suffix) = file_name.to_str().and_then(|name| name.strip_prefix(self.input_name)) else {
+                continue;
+            };
+
+            if (suffix.is_empty() || suffix.starts_with('_')) && entry.file_type()?.is_file() {
+                inputs.push((suffix.to_string(), entry.path()));
+            }
+        }
+
+        // The main input has an empty suffix and goes first
+        inputs.sort();
+        Ok(inputs.into_iter().map(|(_, path)| path).collect())
+    }
+}
+
+/// Declare the solvers table. Each line gives the day, its input file name and its title.
+macro_rules! register_solvers {
+    ($($day:literal => $solver:ty, $file:literal, $title:literal;)*) => {
+        static SOLVERS: &[SolverInfo] = &[
+            $(
+                SolverInfo {
+                    day: $day,
+                    title: $title,
+                    input_name: $file,
+                    example_input: concat!("input_examples/", $file),
+                    real_input: concat!("inputs/", $file),
+                    solver: &Solver::<$solver>::NEW,
+                },
+            )*
+        ];
+    };
+}
+
+register_solvers! {
+    1 => Day01, "day01", "Historian Hysteria";
+    2 => Day02, "day02", "Red-Nosed Reports";
+    3 => Day03, "day03", "Mull It Over";
+    4 => Day04, "day04", "Ceres Search";
+    5 => Day05, "day05", "Print Queue";
+    6 => Day06, "day06", "Guard Gallivant";
+    7 => Day07, "day07", "Bridge Repair";
+    8 => Day08, "day08", "Resonant Collinearity";
+    9 => Day09, "day09", "Disk Fragmenter";
+    10 => Day10, "day10", "Hoof It";
+    11 => Day11, "day11", "Plutonian Pebbles";
+    12 => Day12, "day12", "Garden Groups";
+    13 => Day13, "day13", "Claw Contraption";
+    14 => Day14, "day14", "Restroom Redoubt";
+    15 => Day15, "day15", "Warehouse Woes";
+    16 => Day16, "day16", "Reindeer Maze";
+    17 => Day17, "day17", "Chronospatial Computer";
+    18 => Day18, "day18", "RAM Run";
+    19 => Day19, "day19", "Linen Layout";
+    20 => Day20, "day20", "Race Condition";
+    21 => Day21, "day21", "Keypad Conundrum";
+    22 => Day22, "day22", "Monkey Market";
+    23 => Day23, "day23", "LAN Party";
+    24 => Day24, "day24", "Crossed Wires";
+    25 => Day25, "day25", "Code Chronicle";
+}
+
+/// Every registered solver, sorted by day
+pub fn all() -> &'static [SolverInfo] {
+    SOLVERS
+}
+
+/// Find the solver of a given day
+pub fn get(day: usize) -> Option<&'static SolverInfo> {
+    SOLVERS.iter().find(|info| info.day == day)
+}
@@ -0,0 +1,62 @@
+use std::path::{Path, PathBuf};
+
+use crate::models::Answer;
+use crate::registry::{all, get};
+
+#[test]
+fn test_days_are_sorted_and_unique() {
+    let days: Vec<_> = all().iter().map(|info| info.day).collect();
+    assert_eq!(days.first(), Some(&1));
+    assert!(
+        days.windows(2).all(|pair| pair[0] < pair[1]),
+        "days are not strictly increasing: {days:?}"
+    );
+}
+
+#[test]
+fn test_get() {
+    assert!(get(0).is_none());
+    // Days go up to 99
+    assert!(get(100).is_none());
+
+    let info = get(1).unwrap();
+    assert_eq!(info.title, "Historian Hysteria");
+    assert_eq!(info.example_input, "input_examples/day01");
+    assert_eq!(info.real_input, "inputs/day01");
+}
+
+#[test]
+fn test_solver() {
+    let solver = get(1).unwrap().solver();
+    let parsed = solver
+        .parse(include_str!("../../input_examples/day01").to_string())
+        .unwrap();
+    assert_eq!(parsed.prepare().solve_part_one(), Answer::Integer(11));
+}
+
+#[test]
+fn test_solver_is_shared() {
+    // The same solver handles several inputs at the same time
+    let solver = get(1).unwrap().solver();
+    let example = solver
+        .parse(include_str!("../../input_examples/day01").to_string())
+        .unwrap();
+    let other = solver.parse("1   2\n".to_string()).unwrap();
+
+    assert_eq!(other.prepare().solve_part_one(), Answer::Integer(1));
+    assert_eq!(example.prepare().solve_part_two(), Answer::Integer(31));
+}
+
+#[test]
+fn test_find_inputs() {
+    let inputs = get(4).unwrap().find_inputs(Path::new("input_examples")).unwrap();
+    assert_eq!(
+        inputs,
+        vec![
+            PathBuf::from("input_examples/day04"),
+            PathBuf::from("input_examples/day04_small"),
+        ]
+    );
+
+    assert!(get(1).unwrap().find_inputs(Path::new("does_not_exist")).is_err());
+}
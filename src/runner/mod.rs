@@ -0,0 +1,108 @@
+pub mod output;
+mod parallel;
+
+#[cfg(test)]
+mod tests;
+
+use std::time::Instant;
+
+pub use parallel::{run_days, DayJob, DayResult};
+
+use crate::errors::ParseError;
+use crate::memory;
+use crate::memory::DayMemory;
+use crate::models::{Answer, DynSolution};
+
+/// Answers and timings of a day, every time is in microseconds
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RunDaySolution {
+    pub day: usize,
+    /// Name of the input file, empty if unknown
+    pub input: String,
+    pub part_01_sol: Answer,
+    pub part_02_sol: Answer,
+    pub parse_time: u128,
+    pub prep_time: u128,
+    pub part_01_time: u128,
+    pub part_02_time: u128,
+    /// Memory used by each phase, only measured when the counting allocator is enabled
+    pub memory: Option<DayMemory>,
+}
+
+impl RunDaySolution {
+    pub fn total_time(&self) -> u128 {
+        self.parse_time + self.prep_time + self.part_01_time + self.part_02_time
+    }
+}
+
+/// Puzzle part to run
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub fn run_day(day: usize, solver: &dyn DynSolution, input: String) -> Result<RunDaySolution, ParseError> {
+    run_day_part(day, solver, input, None)
+}
+
+/// Run a day, or only one of its parts if `part` is set.
+///
+/// A part that is not run has no answer and a null time.
+pub fn run_day_part(
+    day: usize,
+    solver: &dyn DynSolution,
+    input: String,
+    part: Option<Part>,
+) -> Result<RunDaySolution, ParseError> {
+    let track_memory = memory::is_enabled();
+    let mut day_memory = DayMemory::default();
+
+    // Parse the data
+    memory::start_phase();
+    let now = Instant::now();
+    let parsed = solver.parse(input)?;
+    let parse_time = now.elapsed().as_micros();
+    day_memory.parse = memory::end_phase();
+
+    // Prepare the parsed_data
+    memory::start_phase();
+    let now = Instant::now();
+    let prepared = parsed.prepare();
+    let prep_time = now.elapsed().as_micros();
+    day_memory.prepare = memory::end_phase();
+
+    // Solve part one
+    memory::start_phase();
+    let (part_01_sol, part_01_time) = if part != Some(Part::Two) {
+        let now = Instant::now();
+        let part_01_sol = prepared.solve_part_one();
+        (part_01_sol, now.elapsed().as_micros())
+    } else {
+        (Answer::NotApplicable, 0)
+    };
+    day_memory.part_one = memory::end_phase();
+
+    // Solve part two
+    memory::start_phase();
+    let (part_02_sol, part_02_time) = if part != Some(Part::One) {
+        let now = Instant::now();
+        let part_02_sol = prepared.solve_part_two();
+        (part_02_sol, now.elapsed().as_micros())
+    } else {
+        (Answer::NotApplicable, 0)
+    };
+    day_memory.part_two = memory::end_phase();
+
+    Ok(RunDaySolution {
+        day,
+        input: String::new(),
+        part_01_sol,
+        part_02_sol,
+        parse_time,
+        prep_time,
+        part_01_time,
+        part_02_time,
+        memory: track_memory.then_some(day_memory),
+    })
+}
@@ -0,0 +1,220 @@
+//! Machine-readable representations of run results
+
+use std::fmt::Write;
+
+use crate::errors::ParseError;
+use crate::models::Answer;
+use crate::runner::RunDaySolution;
+
+pub const CSV_HEADER: &str =
+    "day,input,part_01_sol,part_02_sol,parse_time,prep_time,part_01_time,part_02_time,total_time";
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Numbers are kept as JSON numbers, missing answers become `null`
+fn answer_to_json(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(value) => value.to_string(),
+        Answer::BigInteger(value) => value.to_string(),
+        Answer::Text(value) => escape_json(value),
+        Answer::NotApplicable => "null".to_string(),
+    }
+}
+
+/// Fields containing a separator, a quote or a line break are quoted, missing answers are left empty
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write an answer as a CSV field, its type is not kept
+pub fn answer_to_csv(answer: &Answer) -> String {
+    match answer {
+        Answer::NotApplicable => String::new(),
+        answer => escape_csv(&answer.to_string()),
+    }
+}
+
+/// Serialize a run as a single line JSON object
+pub fn to_json(solution: &RunDaySolution) -> String {
+    format!(
+        "{{\"day\":{},\"input\":{},\"part_01_sol\":{},\"part_02_sol\":{},\"parse_time\":{},\"prep_time\":{},\
+        \"part_01_time\":{},\"part_02_time\":{},\"total_time\":{}}}",
+        solution.day,
+        escape_json(&solution.input),
+        answer_to_json(&solution.part_01_sol),
+        answer_to_json(&solution.part_02_sol),
+        solution.parse_time,
+        solution.prep_time,
+        solution.part_01_time,
+        solution.part_02_time,
+        solution.total_time()
+    )
+}
+
+/// Serialize several runs as a JSON array, one run per line
+pub fn to_json_array(solutions: &[RunDaySolution]) -> String {
+    if solutions.is_empty() {
+        return "[]".to_string();
+    }
+
+    let objects: Vec<_> = solutions
+        .iter()
+        .map(|solution| format!("  {}", to_json(solution)))
+        .collect();
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+/// Serialize a run as a CSV record, without the header
+pub fn to_csv_record(solution: &RunDaySolution) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{}",
+        solution.day,
+        escape_csv(&solution.input),
+        answer_to_csv(&solution.part_01_sol),
+        answer_to_csv(&solution.part_02_sol),
+        solution.parse_time,
+        solution.prep_time,
+        solution.part_01_time,
+        solution.part_02_time,
+        solution.total_time()
+    )
+}
+
+/// Serialize several runs as a CSV document, with its header
+pub fn to_csv(solutions: &[RunDaySolution]) -> String {
+    let mut csv = CSV_HEADER.to_string();
+    for solution in solutions {
+        csv.push('\n');
+        csv.push_str(&to_csv_record(solution));
+    }
+    csv
+}
+
+/// Split a CSV record into its fields, returns the column of the faulty character on error
+fn split_csv_record(record: &str) -> Result<Vec<String>, usize> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = record.chars().enumerate().peekable();
+
+    while let Some((column, c)) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.next_if(|(_, next)| *next == '"').is_some() {
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            '"' => return Err(column),
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(record.chars().count());
+    }
+    fields.push(field);
+
+    Ok(fields)
+}
+
+/// Read back an answer written by `to_csv`, a text made of digits comes back as an integer
+fn answer_from_csv(value: &str) -> Answer {
+    if value.is_empty() {
+        Answer::NotApplicable
+    } else if let Ok(value) = value.parse::<i64>() {
+        Answer::Integer(value)
+    } else if let Ok(value) = value.parse::<u128>() {
+        Answer::BigInteger(value)
+    } else {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Parse a CSV document written by `to_csv`, the total time column is recomputed from the phases
+pub fn from_csv(input: &str) -> Result<Vec<RunDaySolution>, ParseError> {
+    let mut lines = input.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header == CSV_HEADER => (),
+        header => {
+            let header = header.map_or("", |(_, header)| header);
+            let column = header
+                .chars()
+                .zip(CSV_HEADER.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            return Err(ParseError::at_line(
+                0,
+                column,
+                header,
+                format!("expected the header {CSV_HEADER}"),
+            ));
+        }
+    }
+
+    let mut solutions = vec![];
+    for (y, line) in lines {
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields = split_csv_record(line).map_err(|x| ParseError::at_line(y, x, line, "invalid quoted field"))?;
+        if fields.len() != 9 {
+            return Err(ParseError::at_line(
+                y,
+                0,
+                line,
+                format!("expected 9 fields, found {}", fields.len()),
+            ));
+        }
+
+        // Column of each field, to locate errors on numbers
+        let number = |index: usize| {
+            fields[index].parse::<u128>().map_err(|_| {
+                let column = fields[..index].iter().map(|field| to_csv_field_len(field) + 1).sum();
+                ParseError::at_line(y, column, line, "expected a number")
+            })
+        };
+
+        solutions.push(RunDaySolution {
+            day: number(0)? as usize,
+            input: fields[1].clone(),
+            part_01_sol: answer_from_csv(&fields[2]),
+            part_02_sol: answer_from_csv(&fields[3]),
+            parse_time: number(4)?,
+            prep_time: number(5)?,
+            part_01_time: number(6)?,
+            part_02_time: number(7)?,
+            memory: None,
+        });
+    }
+
+    Ok(solutions)
+}
+
+/// Length of a field once written in a CSV record
+fn to_csv_field_len(field: &str) -> usize {
+    escape_csv(field).chars().count()
+}
@@ -0,0 +1,111 @@
+use std::collections::VecDeque;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::errors::ParseError;
+use crate::models::DynSolution;
+use crate::registry::SolverInfo;
+use crate::runner::{run_day, RunDaySolution};
+
+/// A day to run with its input
+pub struct DayJob {
+    pub day: usize,
+    /// Name of the input, reported in the solution
+    pub name: String,
+    pub solver: &'static dyn DynSolution,
+    pub input: String,
+}
+
+impl DayJob {
+    pub fn new(info: &SolverInfo, name: impl Into<String>, input: String) -> Self {
+        Self {
+            day: info.day,
+            name: name.into(),
+            solver: info.solver,
+            input,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DayResult {
+    Solved(RunDaySolution),
+    InvalidInput(ParseError),
+    /// The day did not finish before the time limit
+    TimedOut {
+        day: usize,
+        input: String,
+    },
+    /// The solver panicked
+    Panicked {
+        day: usize,
+        input: String,
+    },
+    /// Solved while a timed-out day was still running in the background, the timings are not reliable
+    SkewedTimings(RunDaySolution),
+}
+
+/// Run days on `threads` worker threads, results are returned in the order of the jobs.
+///
+/// Each day runs on its own thread, supervised by a worker. When a day exceeds `timeout`, the worker
+/// reports it as timed out and moves on: the day thread cannot be stopped and is left running in the
+/// background until it finishes or the process exits. Days that run meanwhile compete with it for the
+/// CPU, they are reported with `DayResult::SkewedTimings`.
+pub fn run_days(jobs: Vec<DayJob>, threads: usize, timeout: Option<Duration>) -> Vec<DayResult> {
+    let job_count = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate().collect::<VecDeque<_>>());
+    let results = Mutex::new(vec![None; job_count]);
+    // Threads of the days that timed out
+    let strays: Mutex<Vec<JoinHandle<()>>> = Mutex::new(vec![]);
+    // Number of timed-out days so far, and whether one of them is still running
+    let stray_status = || {
+        let strays = strays.lock().unwrap();
+        (strays.len(), strays.iter().any(|stray| !stray.is_finished()))
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, job_count.max(1)) {
+            scope.spawn(|| loop {
+                // Release the lock before running the day
+                let Some((index, job)) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let (day, input) = (job.day, job.name.clone());
+                let status_before = stray_status();
+
+                let (sender, receiver) = mpsc::channel();
+                let handle = thread::spawn(move || {
+                    let result = run_day(job.day, job.solver, job.input).map(|solution| RunDaySolution {
+                        input: job.name,
+                        ..solution
+                    });
+                    let _ = sender.send(result);
+                });
+
+                // A panicking day drops its sender without sending anything
+                let received = match timeout {
+                    Some(timeout) => receiver.recv_timeout(timeout),
+                    None => receiver.recv().map_err(mpsc::RecvTimeoutError::from),
+                };
+                let status_after = stray_status();
+                let result = match received {
+                    Ok(Ok(solution)) if status_before.1 || status_after != status_before => {
+                        DayResult::SkewedTimings(solution)
+                    }
+                    Ok(Ok(solution)) => DayResult::Solved(solution),
+                    Ok(Err(error)) => DayResult::InvalidInput(error),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        strays.lock().unwrap().push(handle);
+                        DayResult::TimedOut { day, input }
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => DayResult::Panicked { day, input },
+                };
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter().flatten().collect()
+}
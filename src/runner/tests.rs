@@ -0,0 +1,231 @@
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::errors::ParseError;
+use crate::models::{AdventSolution, Answer, Solver};
+use crate::registry::{all, get};
+use crate::runner::output::{to_csv, to_json, to_json_array};
+use crate::runner::{run_day, run_day_part, run_days, DayJob, DayResult, Part, RunDaySolution};
+
+struct SlowDay;
+
+impl AdventSolution for SlowDay {
+    type Parsed = ();
+    type Prepared = ();
+
+    fn parse(_data: String) -> Result<Self::Parsed, ParseError> {
+        Ok(())
+    }
+
+    fn serialize(_parsed: &Self::Parsed) -> String {
+        String::new()
+    }
+
+    fn prepare(_parsed: Self::Parsed) -> Self::Prepared {}
+
+    fn solve_part_one(_prepared: &Self::Prepared) -> Answer {
+        thread::sleep(Duration::from_secs(2));
+        Answer::NotApplicable
+    }
+
+    fn solve_part_two(_prepared: &Self::Prepared) -> Answer {
+        Answer::NotApplicable
+    }
+}
+
+struct PanickingDay;
+
+impl AdventSolution for PanickingDay {
+    type Parsed = ();
+    type Prepared = ();
+
+    fn parse(_data: String) -> Result<Self::Parsed, ParseError> {
+        Ok(())
+    }
+
+    fn serialize(_parsed: &Self::Parsed) -> String {
+        String::new()
+    }
+
+    fn prepare(_parsed: Self::Parsed) -> Self::Prepared {}
+
+    fn solve_part_one(_prepared: &Self::Prepared) -> Answer {
+        panic!("this day panics")
+    }
+
+    fn solve_part_two(_prepared: &Self::Prepared) -> Answer {
+        Answer::NotApplicable
+    }
+}
+
+fn solution(part_01_sol: Answer, part_02_sol: Answer) -> RunDaySolution {
+    RunDaySolution {
+        day: 17,
+        input: "day17".to_string(),
+        part_01_sol,
+        part_02_sol,
+        parse_time: 1,
+        prep_time: 2,
+        part_01_time: 3,
+        part_02_time: 4,
+        memory: None,
+    }
+}
+
+#[test]
+fn test_run_day() {
+    let input = include_str!("../../input_examples/day01").to_string();
+    let solution = run_day(1, get(1).unwrap().solver(), input).unwrap();
+    assert_eq!(solution.part_01_sol, Answer::Integer(11));
+    assert_eq!(solution.part_02_sol, Answer::Integer(31));
+}
+
+#[test]
+fn test_run_day_part() {
+    let input = include_str!("../../input_examples/day01").to_string();
+
+    let solution = run_day_part(1, get(1).unwrap().solver(), input.clone(), Some(Part::One)).unwrap();
+    assert_eq!(solution.part_01_sol, Answer::Integer(11));
+    assert_eq!(
+        (solution.part_02_sol, solution.part_02_time),
+        (Answer::NotApplicable, 0)
+    );
+
+    let solution = run_day_part(1, get(1).unwrap().solver(), input, Some(Part::Two)).unwrap();
+    assert_eq!(
+        (solution.part_01_sol, solution.part_01_time),
+        (Answer::NotApplicable, 0)
+    );
+    assert_eq!(solution.part_02_sol, Answer::Integer(31));
+}
+
+#[test]
+fn test_to_json() {
+    assert_eq!(
+        to_json(&solution("4,6,\"3\"".into(), Answer::NotApplicable)),
+        "{\"day\":17,\"input\":\"day17\",\"part_01_sol\":\"4,6,\\\"3\\\"\",\"part_02_sol\":null,\"parse_time\":1,\"prep_time\":2,\
+        \"part_01_time\":3,\"part_02_time\":4,\"total_time\":10}"
+    );
+    assert_eq!(to_json_array(&[]), "[]");
+}
+
+#[test]
+fn test_to_csv() {
+    assert_eq!(
+        to_csv(&[
+            solution("4,6,\"3\"".into(), Answer::NotApplicable),
+            solution(1.into(), 2.into())
+        ]),
+        "day,input,part_01_sol,part_02_sol,parse_time,prep_time,part_01_time,part_02_time,total_time\n\
+        17,day17,\"4,6,\"\"3\"\"\",,1,2,3,4,10\n\
+        17,day17,1,2,1,2,3,4,10"
+    );
+}
+
+#[test]
+fn test_run_days() {
+    let jobs = (1..=5)
+        .rev()
+        .map(|day| {
+            let info = get(day).unwrap();
+            DayJob::new(
+                info,
+                info.input_name,
+                std::fs::read_to_string(info.example_input).unwrap(),
+            )
+        })
+        .collect();
+
+    let results = run_days(jobs, 3, None);
+    let days: Vec<_> = results
+        .iter()
+        .map(|result| match result {
+            DayResult::Solved(solution) => (solution.day, solution.input.as_str()),
+            result => panic!("unexpected result {result:?}"),
+        })
+        .collect();
+    assert_eq!(
+        days,
+        vec![(5, "day05"), (4, "day04"), (3, "day03"), (2, "day02"), (1, "day01")]
+    );
+}
+
+#[test]
+fn test_run_days_on_every_example() {
+    // This is what the all subcommand runs by default
+    let jobs = all()
+        .iter()
+        .flat_map(|info| {
+            let paths = info.find_inputs(Path::new("input_examples")).unwrap();
+            paths.into_iter().map(move |path| {
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                DayJob::new(info, name, std::fs::read_to_string(path).unwrap())
+            })
+        })
+        .collect();
+
+    for result in run_days(jobs, 4, None) {
+        assert!(matches!(result, DayResult::Solved(_)), "unexpected result {result:?}");
+    }
+}
+
+#[test]
+fn test_run_days_failures() {
+    let jobs = vec![
+        DayJob {
+            day: 1,
+            name: "slow".to_string(),
+            solver: &Solver::<SlowDay>::NEW,
+            input: String::new(),
+        },
+        DayJob {
+            day: 2,
+            name: "panicking".to_string(),
+            solver: &Solver::<PanickingDay>::NEW,
+            input: String::new(),
+        },
+        DayJob::new(get(3).unwrap(), "empty", String::new()),
+        DayJob::new(get(4).unwrap(), "empty", String::new()),
+    ];
+
+    let results = run_days(jobs, 2, Some(Duration::from_millis(200)));
+    assert_eq!(
+        results[0],
+        DayResult::TimedOut {
+            day: 1,
+            input: "slow".to_string(),
+        }
+    );
+    assert_eq!(
+        results[1],
+        DayResult::Panicked {
+            day: 2,
+            input: "panicking".to_string(),
+        }
+    );
+    assert!(matches!(results[2], DayResult::Solved(_)));
+    assert!(matches!(results[3], DayResult::InvalidInput(_)));
+}
+
+#[test]
+fn test_run_days_after_a_timeout() {
+    let example = std::fs::read_to_string(get(3).unwrap().example_input).unwrap();
+    let jobs = vec![
+        DayJob {
+            day: 1,
+            name: "slow".to_string(),
+            solver: &Solver::<SlowDay>::NEW,
+            input: String::new(),
+        },
+        DayJob::new(get(3).unwrap(), "day03", example),
+    ];
+
+    // The slow day is still running in the background when the next one is solved
+    let results = run_days(jobs, 1, Some(Duration::from_millis(200)));
+    assert!(matches!(results[0], DayResult::TimedOut { .. }));
+    let DayResult::SkewedTimings(solution) = &results[1] else {
+        panic!("unexpected result {:?}", results[1]);
+    };
+    assert_eq!(solution.part_01_sol, Answer::Integer(161));
+}
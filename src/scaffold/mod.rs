@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+/// Files of the `src/dayxx` template module
+const TEMPLATE_FILES: [&str; 7] = [
+    "mod.rs",
+    "generator.rs",
+    "logic.rs",
+    "models.rs",
+    "parser.rs",
+    "serializer.rs",
+    "tests.rs",
+];
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Substitute the template names in a template file
+fn instantiate(template: &str, day: usize) -> String {
+    template
+        .replace("dayxx", &format!("day{day:0>2}"))
+        .replace("DayXX", &format!("Day{day:0>2}"))
+        .replace("with_day(0)", &format!("with_day({day})"))
+        .replace(
+            "static INPUT_EXAMPLE: &str = \"\";",
+            &format!("static INPUT_EXAMPLE: &str = include_str!(\"../../input_examples/day{day:0>2}\");"),
+        )
+}
+
+/// Insert `line` among the lines matching `pattern`, whose first capture is a day number, keeping them
+/// sorted by day
+fn insert_sorted(content: &str, pattern: &Regex, day: usize, line: &str) -> io::Result<String> {
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut last_match = None;
+    let mut position = None;
+    for (i, current) in lines.iter().enumerate() {
+        let Some(captures) = pattern.captures(current) else {
+            continue;
+        };
+        let current_day: usize = captures[1].parse().unwrap_or_default();
+        if current_day == day {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("day {day} is already registered"),
+            ));
+        }
+        if current_day > day && position.is_none() {
+            position = Some(i);
+        }
+        last_match = Some(i);
+    }
+
+    let Some(position) = position.or(last_match.map(|i| i + 1)) else {
+        return Err(invalid_input(format!("unable to find where to register day {day}")));
+    };
+
+    let mut result: Vec<&str> = lines[..position].to_vec();
+    result.push(line);
+    result.extend_from_slice(&lines[position..]);
+    Ok(result.join("\n") + "\n")
+}
+
+/// Create the module of a new day from the `src/dayxx` template and register it.
+///
+/// `root` is the crate root. The module is declared in `src/lib.rs`, added to the solvers of
+/// `src/registry/mod.rs` and gets empty input files. Returns the created files.
+pub fn scaffold_day(root: &Path, day: usize, title: &str) -> io::Result<Vec<PathBuf>> {
+    if !(1..=99).contains(&day) {
+        return Err(invalid_input(format!("day must be between 1 and 99, got {day}")));
+    }
+
+    let name = format!("day{day:0>2}");
+    let module_dir = root.join("src").join(&name);
+    if module_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_dir.display()),
+        ));
+    }
+
+    // Compute every edit before writing anything so that a failure leaves the crate untouched
+    let lib_path = root.join("src/lib.rs");
+    let lib = insert_sorted(
+        &fs::read_to_string(&lib_path)?,
+        &Regex::new(r"^pub mod day(\d+);$").unwrap(),
+        day,
+        &format!("pub mod {name};"),
+    )?;
+
+    let registry_path = root.join("src/registry/mod.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    let registry = insert_sorted(
+        &registry,
+        &Regex::new(r"^use crate::day(\d+)::Day\d+;$").unwrap(),
+        day,
+        &format!("use crate::{name}::Day{day:0>2};"),
+    )?;
+    let registry = insert_sorted(
+        &registry,
+        &Regex::new(r"^\s+(\d+) => Day\d+,").unwrap(),
+        day,
+        &format!(
+            "    {day} => Day{day:0>2}, \"{name}\", \"{}\";",
+            title.replace('"', "\\\"")
+        ),
+    )?;
+
+    let template_dir = root.join("src/dayxx");
+    let mut files = vec![];
+    for file in TEMPLATE_FILES {
+        let template = fs::read_to_string(template_dir.join(file))?;
+        files.push((module_dir.join(file), instantiate(&template, day)));
+    }
+
+    // Write the new day
+    fs::create_dir_all(&module_dir)?;
+    let mut created = vec![];
+    for (path, content) in files {
+        fs::write(&path, content)?;
+        created.push(path);
+    }
+
+    for directory in ["input_examples", "inputs"] {
+        fs::create_dir_all(root.join(directory))?;
+        let path = root.join(directory).join(&name);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    fs::write(lib_path, lib)?;
+    fs::write(registry_path, registry)?;
+
+    Ok(created)
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::registry;
+use crate::scaffold::{scaffold_day, TEMPLATE_FILES};
+
+/// Copy the files needed by the scaffold to a temporary crate root
+fn temporary_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("advent_2024_scaffold_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    fs::create_dir_all(root.join("src/dayxx")).unwrap();
+    fs::create_dir_all(root.join("src/registry")).unwrap();
+    for file in TEMPLATE_FILES {
+        fs::copy(format!("src/dayxx/{file}"), root.join("src/dayxx").join(file)).unwrap();
+    }
+    fs::copy("src/lib.rs", root.join("src/lib.rs")).unwrap();
+    fs::copy("src/registry/mod.rs", root.join("src/registry/mod.rs")).unwrap();
+
+    root
+}
+
+#[test]
+fn test_scaffold_day() {
+    let root = temporary_root("new");
+    // The first day without a module, the crate keeps growing
+    let previous = registry::all().last().unwrap().day;
+    let day = previous + 1;
+
+    let created = scaffold_day(&root, day, "Next \"Puzzle\"").unwrap();
+    assert_eq!(created.len(), 9);
+
+    let module = fs::read_to_string(root.join(format!("src/day{day:0>2}/mod.rs"))).unwrap();
+    assert!(module.contains(&format!("pub struct Day{day:0>2}")));
+    assert!(module.contains(&format!("use crate::day{day:0>2}::parser::parse_input;")));
+    assert!(module.contains(&format!("with_day({day})")));
+    assert!(!module.to_lowercase().contains("dayxx"));
+
+    let tests = fs::read_to_string(root.join(format!("src/day{day:0>2}/tests.rs"))).unwrap();
+    assert!(tests.contains(&format!("include_str!(\"../../input_examples/day{day:0>2}\")")));
+    assert!(root.join(format!("input_examples/day{day:0>2}")).exists());
+    assert!(root.join(format!("inputs/day{day:0>2}")).exists());
+
+    let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert!(lib.contains(&format!("pub mod day{previous:0>2};\npub mod day{day:0>2};\n")));
+
+    let registry = fs::read_to_string(root.join("src/registry/mod.rs")).unwrap();
+    assert!(registry.contains(&format!(
+        "use crate::day{previous:0>2}::Day{previous:0>2};\nuse crate::day{day:0>2}::Day{day:0>2};\n"
+    )));
+    assert!(registry.contains(&format!(
+        "    {day} => Day{day:0>2}, \"day{day:0>2}\", \"Next \\\"Puzzle\\\"\";\n}}"
+    )));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_scaffold_existing_day() {
+    let root = temporary_root("existing");
+    let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+
+    assert!(scaffold_day(&root, 3, "Mull It Over").is_err());
+    assert!(scaffold_day(&root, 0, "Invalid").is_err());
+
+    // Nothing was written
+    assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), lib);
+    assert!(!root.join("src/day03").exists());
+
+    fs::remove_dir_all(root).unwrap();
+}
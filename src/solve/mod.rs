@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests;
+
+use crate::errors::SolveError;
+use crate::models::Answer;
+use crate::registry;
+
+/// Answers of both parts of a day
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DayReport {
+    pub day: usize,
+    pub title: &'static str,
+    pub part_one: Answer,
+    pub part_two: Answer,
+}
+
+/// Solve both parts of a day, this is the entry point for the crates that embed the solvers.
+///
+/// The answers are converted to `Answer` so that every day has the same signature. The day modules
+/// also give typed access to each step, for example `day13::parse` followed by `day13::part_two`.
+pub fn solve(day: usize, input: &str) -> Result<DayReport, SolveError> {
+    let info = registry::get(day).ok_or(SolveError::UnknownDay(day))?;
+
+    let prepared = info.solver().parse(input.to_string())?.prepare();
+
+    Ok(DayReport {
+        day,
+        title: info.title,
+        part_one: prepared.solve_part_one(),
+        part_two: prepared.solve_part_two(),
+    })
+}
@@ -0,0 +1,54 @@
+use crate::errors::SolveError;
+use crate::models::Answer;
+use crate::{day13, day19, solve};
+
+static DAY01_EXAMPLE: &str = include_str!("../../input_examples/day01");
+static DAY13_EXAMPLE: &str = include_str!("../../input_examples/day13");
+static DAY19_EXAMPLE: &str = include_str!("../../input_examples/day19");
+
+#[test]
+fn test_solve() {
+    let report = solve(1, DAY01_EXAMPLE).unwrap();
+
+    assert_eq!(report.day, 1);
+    assert_eq!(report.title, "Historian Hysteria");
+    assert_eq!(report.part_one, Answer::Integer(11));
+    assert_eq!(report.part_two, Answer::Integer(31));
+}
+
+#[test]
+fn test_solve_unknown_day() {
+    assert_eq!(solve(100, DAY01_EXAMPLE), Err(SolveError::UnknownDay(100)));
+}
+
+#[test]
+fn test_solve_invalid_input() {
+    let Err(SolveError::InvalidInput(error)) = solve(1, "3   4\n4   x\n") else {
+        panic!("expected an invalid input error");
+    };
+
+    assert_eq!(error.day, Some(1));
+    assert_eq!((error.line, error.column), (2, 5));
+}
+
+#[test]
+fn test_typed_api() {
+    let machines = day13::parse(DAY13_EXAMPLE).unwrap();
+
+    assert_eq!(machines.len(), 4);
+    assert_eq!(machines[0].target, (8400, 5400));
+    assert_eq!(day13::part_one(&machines), 480);
+    assert_eq!(day13::part_two(&machines), 875318608908);
+    assert_eq!(
+        solve(13, DAY13_EXAMPLE).unwrap().part_two,
+        day13::part_two(&machines).into()
+    );
+}
+
+#[test]
+fn test_typed_api_with_a_shared_preparation() {
+    let counts = day19::prepare(&day19::parse(DAY19_EXAMPLE).unwrap());
+
+    assert_eq!(day19::part_one(&counts), 6);
+    assert_eq!(day19::part_two(&counts), 16);
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests;
+
+use crate::errors::ParseError;
+use crate::models::Answer;
+use crate::runner::RunDaySolution;
+
+/// Expected answer of a puzzle part, as stored in an answers file
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpectedAnswer {
+    pub day: usize,
+    pub part: usize,
+    pub expected: String,
+}
+
+/// Result of the comparison between an expected answer and the computed one
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartCheck {
+    pub day: usize,
+    pub part: usize,
+    pub expected: String,
+    pub actual: Answer,
+    /// Time spent solving the part, in microseconds
+    pub time: u128,
+}
+
+impl PartCheck {
+    pub fn passed(&self) -> bool {
+        self.actual.to_string() == self.expected
+    }
+}
+
+/// Parse an answers file.
+///
+/// Each line contains a day, a part and the expected answer separated by spaces, for example `17 1 4,6,3`.
+/// Empty lines and lines starting with `#` are ignored.
+pub fn parse_answers(input: &str) -> Result<Vec<ExpectedAnswer>, ParseError> {
+    let mut answers = vec![];
+
+    for (y, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        let mut fields = trimmed.splitn(3, ' ');
+        let day_text = fields.next().unwrap_or_default();
+        let part = fields.next().unwrap_or_default();
+        let expected = fields.next().unwrap_or_default().trim();
+
+        let Ok(day) = day_text.parse() else {
+            return Err(ParseError::at_line(y, indent, line, "expected a day number"));
+        };
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => {
+                let column = (indent + day_text.len() + 1).min(line.len());
+                return Err(ParseError::at_line(y, column, line, "expected part 1 or 2"));
+            }
+        };
+        if expected.is_empty() {
+            return Err(ParseError::at_line(y, line.len(), line, "expected an answer"));
+        }
+
+        answers.push(ExpectedAnswer {
+            day,
+            part,
+            expected: expected.to_string(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// Compare the answers of a run with the expected answers of its day
+pub fn check_day(solution: &RunDaySolution, answers: &[ExpectedAnswer]) -> Vec<PartCheck> {
+    answers
+        .iter()
+        .filter(|answer| answer.day == solution.day)
+        .map(|answer| {
+            let (actual, time) = match answer.part {
+                1 => (solution.part_01_sol.clone(), solution.part_01_time),
+                _ => (solution.part_02_sol.clone(), solution.part_02_time),
+            };
+            PartCheck {
+                day: answer.day,
+                part: answer.part,
+                expected: answer.expected.clone(),
+                actual,
+                time,
+            }
+        })
+        .collect()
+}
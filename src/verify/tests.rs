@@ -0,0 +1,61 @@
+use crate::models::Answer;
+use crate::runner::RunDaySolution;
+use crate::verify::{check_day, parse_answers, ExpectedAnswer};
+
+static ANSWERS_EXAMPLE: &str = include_str!("../../input_examples/answers.txt");
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers("# comment\n\n1 1 11\n17 1 4,6,3\n").unwrap();
+    assert_eq!(
+        answers,
+        vec![
+            ExpectedAnswer {
+                day: 1,
+                part: 1,
+                expected: "11".to_string(),
+            },
+            ExpectedAnswer {
+                day: 17,
+                part: 1,
+                expected: "4,6,3".to_string(),
+            },
+        ]
+    );
+
+    assert!(!parse_answers(ANSWERS_EXAMPLE).unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_answers_error() {
+    let error = parse_answers("1 1 11\n1 3 31\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+
+    let error = parse_answers("x 1 11\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+
+    let error = parse_answers("1 1\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 4));
+}
+
+#[test]
+fn test_check_day() {
+    let solution = RunDaySolution {
+        day: 1,
+        input: "day01".to_string(),
+        part_01_sol: Answer::Integer(11),
+        part_02_sol: Answer::Integer(30),
+        parse_time: 0,
+        prep_time: 0,
+        part_01_time: 1,
+        part_02_time: 2,
+        memory: None,
+    };
+    let answers = parse_answers("1 1 11\n1 2 31\n2 1 2\n").unwrap();
+
+    let checks = check_day(&solution, &answers);
+    assert_eq!(checks.len(), 2);
+    assert!(checks[0].passed());
+    assert!(!checks[1].passed());
+    assert_eq!(checks[1].time, 2);
+}
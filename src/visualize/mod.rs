@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests;
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use colored::ColoredString;
+
+use crate::grid::{Coordinates, Grid};
+
+/// Clear the terminal and move the cursor to the top left corner
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PlayConfig {
+    /// Pause after each displayed frame
+    pub delay: Duration,
+    /// Display one frame out of `every`, the last frame is always displayed
+    pub every: usize,
+    /// Clear the terminal before each frame instead of printing frames one after the other
+    pub clear: bool,
+}
+
+impl Default for PlayConfig {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(50),
+            every: 1,
+            clear: true,
+        }
+    }
+}
+
+/// Display frames one after the other, returns the number of displayed frames
+pub fn play<W: Write>(frames: impl Iterator<Item = String>, config: &PlayConfig, out: &mut W) -> io::Result<usize> {
+    let mut frames = frames.enumerate().peekable();
+    let mut displayed = 0;
+
+    while let Some((i, frame)) = frames.next() {
+        let is_last = frames.peek().is_none();
+        if i % config.every.max(1) != 0 && !is_last {
+            continue;
+        }
+
+        if config.clear {
+            write!(out, "{CLEAR_SCREEN}")?;
+        }
+        writeln!(out, "{frame}")?;
+        writeln!(out, "frame {}", i + 1)?;
+        out.flush()?;
+        displayed += 1;
+
+        if !is_last {
+            thread::sleep(config.delay);
+        }
+    }
+
+    Ok(displayed)
+}
+
+/// Render a grid line by line, `render_cell` gives the coloured character of each cell
+pub fn render_grid<T, F>(grid: &Grid<T>, mut render_cell: F) -> String
+where
+    F: FnMut(Coordinates, &T) -> ColoredString,
+{
+    let mut frame = String::with_capacity(grid.width() * grid.height());
+    for (position, cell) in grid.iter() {
+        frame.push_str(&render_cell(position, cell).to_string());
+        if position.x + 1 == grid.width() {
+            frame.push('\n');
+        }
+    }
+    frame
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use colored::Colorize;
+
+use crate::grid::Grid;
+use crate::visualize::{play, render_grid, PlayConfig};
+
+#[test]
+fn test_play() {
+    let frames = (1..=10).map(|i| i.to_string());
+    let config = PlayConfig {
+        delay: Duration::ZERO,
+        every: 4,
+        clear: false,
+    };
+
+    let mut out = vec![];
+    let displayed = play(frames, &config, &mut out).unwrap();
+
+    // Frames 1, 5 and 9 plus the last one
+    assert_eq!(displayed, 4);
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out, "1\nframe 1\n5\nframe 5\n9\nframe 9\n10\nframe 10\n");
+}
+
+#[test]
+fn test_render_grid() {
+    colored::control::set_override(false);
+
+    let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]);
+    let frame = render_grid(&grid, |_, cell| if *cell { "#".red() } else { ".".normal() });
+
+    assert_eq!(frame, "#.\n.#\n");
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Poll files for changes of their modification time, without file system notifications.
+///
+/// Targets are files or directories. The files directly inside a watched directory are watched,
+/// including the ones created after the watcher, but not its sub directories.
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    targets: Vec<PathBuf>,
+    /// Modification time of every existing watched file
+    snapshot: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    pub fn new(targets: Vec<PathBuf>) -> Self {
+        let snapshot = snapshot(&targets);
+        Self { targets, snapshot }
+    }
+
+    /// Files modified, created or removed since the previous call, sorted by path
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let snapshot = snapshot(&self.targets);
+
+        let mut changes: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, modified)| self.snapshot.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changes.extend(
+            self.snapshot
+                .keys()
+                .filter(|path| !snapshot.contains_key(*path))
+                .cloned(),
+        );
+        changes.sort();
+
+        self.snapshot = snapshot;
+        changes
+    }
+
+    /// Wait until at least one file changes, checking every `interval`
+    pub fn wait(&mut self, interval: Duration) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(interval);
+            let changes = self.changes();
+            if !changes.is_empty() {
+                return changes;
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Modification time of the watched files, files that cannot be read are ignored
+fn snapshot(targets: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut snapshot = BTreeMap::new();
+    for target in targets {
+        if !target.is_dir() {
+            if let Some(modified) = modified(target) {
+                snapshot.insert(target.clone(), modified);
+            }
+            continue;
+        }
+
+        let Ok(entries) = fs::read_dir(target) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if let Some(modified) = modified(&path).filter(|_| path.is_file()) {
+                snapshot.insert(path, modified);
+            }
+        }
+    }
+
+    snapshot
+}
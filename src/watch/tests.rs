@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::watch::Watcher;
+
+fn temporary_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("advent_2024_watch_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// Write a file with an explicit modification time, file systems may have a coarse time resolution
+fn write(path: &PathBuf, content: &str, seconds: u64) {
+    fs::write(path, content).unwrap();
+    let file = fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+        .unwrap();
+}
+
+#[test]
+fn test_watch_file() {
+    let directory = temporary_directory("file");
+    let input = directory.join("day01");
+    write(&input, "3   4\n", 1);
+
+    let mut watcher = Watcher::new(vec![input.clone()]);
+    assert!(watcher.changes().is_empty());
+
+    write(&input, "3   5\n", 2);
+    assert_eq!(watcher.changes(), vec![input.clone()]);
+    assert!(watcher.changes().is_empty());
+
+    // Other files of the directory are not watched
+    write(&directory.join("day02"), "1 2\n", 3);
+    assert!(watcher.changes().is_empty());
+
+    fs::remove_file(&input).unwrap();
+    assert_eq!(watcher.changes(), vec![input.clone()]);
+    write(&input, "3   4\n", 4);
+    assert_eq!(watcher.wait(Duration::ZERO), vec![input]);
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_watch_directory() {
+    let directory = temporary_directory("directory");
+    let first = directory.join("day04");
+    let second = directory.join("day04_small");
+    write(&first, "XMAS\n", 1);
+    fs::create_dir(directory.join("nested")).unwrap();
+
+    let mut watcher = Watcher::new(vec![directory.clone()]);
+    assert!(watcher.changes().is_empty());
+
+    write(&second, "XMAS\n", 2);
+    assert_eq!(watcher.changes(), vec![second.clone()]);
+
+    write(&first, "SAMX\n", 3);
+    write(&second, "SAMX\n", 3);
+    assert_eq!(watcher.changes(), vec![first, second]);
+
+    // Sub directories are not watched
+    write(&directory.join("nested/day04"), "XMAS\n", 4);
+    assert!(watcher.changes().is_empty());
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,40 @@
+//! Allocation counting needs the counting allocator installed for the whole binary, these tests run
+//! apart from the tests of the library for this reason.
+
+use std::hint::black_box;
+
+use advent_2024::day01::Day01;
+use advent_2024::memory::{end_phase, set_enabled, start_phase, CountingAllocator};
+use advent_2024::models::Solver;
+use advent_2024::runner::run_day;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_phase() {
+    // Other tests may allocate at the same time, only lower bounds can be checked
+    set_enabled(true);
+
+    start_phase();
+    let data = black_box(vec![0u8; 1 << 20]);
+    drop(data);
+    let stats = end_phase();
+
+    assert!(stats.allocations >= 1);
+    assert!(stats.allocated_bytes >= 1 << 20);
+    assert!(stats.peak_bytes >= 1 << 20);
+}
+
+#[test]
+fn test_run_day_reports_memory() {
+    set_enabled(true);
+
+    let input = include_str!("../input_examples/day01").to_string();
+    let solution = run_day(1, &Solver::<Day01>::NEW, input).unwrap();
+
+    let memory = solution
+        .memory
+        .expect("memory should be measured once counting is enabled");
+    assert!(memory.parse.allocations >= 1);
+}